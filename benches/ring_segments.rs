@@ -0,0 +1,138 @@
+//! Benchmarks for bulk transfers that span both segments of a wrapped ring-buffer.
+//!
+//! Each benchmark compares a single bulk call, which copies into or out of both segments at
+//! once, with the one-segment-per-call behaviour of the default implementations of the
+//! [`Queue`] trait, which takes two calls. Run with `cargo bench`.
+
+#![feature(test)]
+
+extern crate test;
+
+use core::cmp::min;
+
+use test::{black_box, Bencher};
+use ufotofu_queues::{Fixed, Queue, Static};
+
+const CAPACITY: usize = 1024;
+
+/// Enqueue like the default `bulk_enqueue`, one contiguous segment of free slots per call, until
+/// the buffer is exhausted or the queue is full.
+fn enqueue_one_segment_per_call<Q: Queue<Item = u8>>(queue: &mut Q, mut buffer: &[u8]) {
+    while let Some(slots) = queue.expose_slots() {
+        let amount = min(slots.len(), buffer.len());
+        slots[..amount].write_copy_of_slice(&buffer[..amount]);
+        unsafe {
+            queue.consider_enqueued(amount);
+        }
+
+        buffer = &buffer[amount..];
+        if buffer.is_empty() {
+            break;
+        }
+    }
+}
+
+/// Dequeue like the default `bulk_dequeue`, one contiguous segment of items per call, until the
+/// buffer is full or the queue is empty.
+fn dequeue_one_segment_per_call<Q: Queue<Item = u8>>(queue: &mut Q, mut buffer: &mut [u8]) {
+    while let Some(items) = queue.expose_items() {
+        let amount = min(items.len(), buffer.len());
+        buffer[..amount].copy_from_slice(&items[..amount]);
+        queue.consider_dequeued(amount);
+
+        buffer = &mut buffer[amount..];
+        if buffer.is_empty() {
+            break;
+        }
+    }
+}
+
+/// Fill a half-full queue whose free slots wrap around the end of the ring-buffer, then remove
+/// the new items from the back again, which restores the initial layout.
+macro_rules! bench_enqueue {
+    ($name:ident, $queue:expr, $enqueue:expr) => {
+        #[bench]
+        fn $name(b: &mut Bencher) {
+            let mut queue = $queue;
+            assert_eq!(queue.bulk_enqueue(&[0; 3 * CAPACITY / 4]), 3 * CAPACITY / 4);
+            queue.discard(CAPACITY / 4);
+            let buffer = [7; CAPACITY / 2];
+
+            b.iter(|| {
+                $enqueue(&mut queue, black_box(&buffer[..]));
+                assert!(queue.is_full());
+                queue.truncate(CAPACITY / 2);
+            });
+        }
+    };
+}
+
+bench_enqueue!(
+    fixed_bulk_enqueue_both_segments,
+    Fixed::<u8>::new(CAPACITY),
+    |queue: &mut Fixed<u8>, buffer: &[u8]| assert_eq!(queue.bulk_enqueue(buffer), CAPACITY / 2)
+);
+bench_enqueue!(
+    fixed_bulk_enqueue_one_segment_per_call,
+    Fixed::<u8>::new(CAPACITY),
+    enqueue_one_segment_per_call
+);
+bench_enqueue!(
+    static_bulk_enqueue_both_segments,
+    Static::<u8, CAPACITY>::new(),
+    |queue: &mut Static<u8, CAPACITY>, buffer: &[u8]| assert_eq!(
+        queue.bulk_enqueue(buffer),
+        CAPACITY / 2
+    )
+);
+bench_enqueue!(
+    static_bulk_enqueue_one_segment_per_call,
+    Static::<u8, CAPACITY>::new(),
+    enqueue_one_segment_per_call
+);
+
+/// Drain a full queue whose items wrap around the end of the ring-buffer, then fill it again,
+/// which restores the initial layout.
+macro_rules! bench_dequeue {
+    ($name:ident, $queue:expr, $dequeue:expr) => {
+        #[bench]
+        fn $name(b: &mut Bencher) {
+            let mut queue = $queue;
+            assert_eq!(queue.bulk_enqueue(&[0; CAPACITY / 2]), CAPACITY / 2);
+            queue.discard(CAPACITY / 2);
+            let items = [7; CAPACITY];
+            assert_eq!(queue.bulk_enqueue(&items), CAPACITY);
+            let mut buffer = [0; CAPACITY];
+
+            b.iter(|| {
+                $dequeue(&mut queue, black_box(&mut buffer[..]));
+                assert!(queue.is_empty());
+                assert_eq!(queue.bulk_enqueue(&items), CAPACITY);
+            });
+        }
+    };
+}
+
+bench_dequeue!(
+    fixed_bulk_dequeue_both_segments,
+    Fixed::<u8>::new(CAPACITY),
+    |queue: &mut Fixed<u8>, buffer: &mut [u8]| assert_eq!(queue.bulk_dequeue(buffer), CAPACITY)
+);
+bench_dequeue!(
+    fixed_bulk_dequeue_one_segment_per_call,
+    Fixed::<u8>::new(CAPACITY),
+    dequeue_one_segment_per_call
+);
+bench_dequeue!(
+    static_bulk_dequeue_both_segments,
+    Static::<u8, CAPACITY>::new(),
+    |queue: &mut Static<u8, CAPACITY>, buffer: &mut [u8]| assert_eq!(
+        queue.bulk_dequeue(buffer),
+        CAPACITY
+    )
+);
+bench_dequeue!(
+    static_bulk_dequeue_one_segment_per_call,
+    Static::<u8, CAPACITY>::new(),
+    dequeue_one_segment_per_call
+);
@@ -0,0 +1,123 @@
+//! A [`Static`] queue as the receive buffer of a mock UART, in a `#![no_std]` context.
+//!
+//! The "interrupt service routine" [`on_rx_interrupt`] moves received bytes from the hardware
+//! FIFO straight into the free slots of the queue, via [`Queue::expose_slots`] and
+//! [`Queue::consider_enqueued`]. The "main loop" [`main_loop_step`] dequeues them one at a time.
+//! On real hardware, both would access the same queue, so the main loop would have to mask the
+//! interrupt (or use a critical section) around each of its calls into the queue.
+//!
+//! The example compiles as a library without the standard library:
+//!
+//! ```sh
+//! cargo build --example no_std_uart --no-default-features
+//! ```
+//!
+//! Its tests run the same logic on the host.
+
+#![cfg_attr(not(test), no_std)]
+
+use ufotofu_queues::{Queue, Static};
+
+/// Capacity of the receive buffer.
+pub const RX_CAPACITY: usize = 16;
+
+/// A mock UART whose received bytes are buffered in a queue until the main loop handles them.
+pub struct Uart {
+    rx: Static<u8, RX_CAPACITY>,
+    /// Number of received bytes that were dropped because the buffer was full.
+    overruns: usize,
+}
+
+impl Uart {
+    pub const fn new() -> Self {
+        Uart {
+            rx: Static::new(),
+            overruns: 0,
+        }
+    }
+
+    pub fn overruns(&self) -> usize {
+        self.overruns
+    }
+}
+
+impl Default for Uart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Called when the hardware FIFO holds received bytes. Copies as many of them as fit into the
+/// receive buffer, without an intermediate buffer, and counts the remaining ones as overruns.
+pub fn on_rx_interrupt(uart: &mut Uart, hardware_fifo: &[u8]) {
+    let mut received = hardware_fifo;
+
+    // The free slots may wrap around the end of the ring-buffer, so expose them until the
+    // bytes run out or the queue is full.
+    while !received.is_empty() {
+        let Some(slots) = uart.rx.expose_slots() else {
+            break;
+        };
+
+        let amount = core::cmp::min(slots.len(), received.len());
+        for (slot, byte) in slots.iter_mut().zip(&received[..amount]) {
+            slot.write(*byte);
+        }
+
+        unsafe {
+            uart.rx.consider_enqueued(amount);
+        }
+        received = &received[amount..];
+    }
+
+    uart.overruns += received.len();
+}
+
+/// One iteration of the main loop: handle the next received byte, if any. Return whether there
+/// was a byte.
+pub fn main_loop_step<F: FnMut(u8)>(uart: &mut Uart, mut handle: F) -> bool {
+    match uart.rx.dequeue() {
+        Some(byte) => {
+            handle(byte);
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn main_loop_receives_bytes_from_interrupts_in_order() {
+        let mut uart = Uart::new();
+        let mut received = Vec::new();
+
+        for chunk in b"ufotofu queues!".chunks(4) {
+            on_rx_interrupt(&mut uart, chunk);
+            // The main loop falls behind, handling two bytes per interrupt.
+            main_loop_step(&mut uart, |byte| received.push(byte));
+            main_loop_step(&mut uart, |byte| received.push(byte));
+        }
+        while main_loop_step(&mut uart, |byte| received.push(byte)) {}
+
+        assert_eq!(received, b"ufotofu queues!");
+        assert_eq!(uart.overruns(), 0);
+    }
+
+    #[test]
+    fn counts_overruns_when_the_buffer_is_full() {
+        let mut uart = Uart::new();
+
+        on_rx_interrupt(&mut uart, &[7; RX_CAPACITY + 3]);
+        assert_eq!(uart.overruns(), 3);
+
+        let mut count = 0;
+        while main_loop_step(&mut uart, |byte| {
+            assert_eq!(byte, 7);
+            count += 1;
+        }) {}
+        assert_eq!(count, RX_CAPACITY);
+    }
+}
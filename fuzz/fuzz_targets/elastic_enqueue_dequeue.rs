@@ -0,0 +1,51 @@
+#![no_main]
+use std::collections::VecDeque;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+
+use ufotofu_queues::Elastic;
+use ufotofu_queues::Queue;
+
+#[derive(Debug, Arbitrary)]
+enum Operation<T> {
+    Enqueue(T),
+    Dequeue,
+}
+
+fuzz_target!(|data: (Vec<Operation<u8>>, usize, usize)| {
+    let operations = data.0;
+    let min_capacity = data.1;
+    let capacity = data.2;
+
+    // Restrict capacities to between 1 and 2048 bytes (inclusive).
+    if min_capacity < 1 || capacity > 2048 || min_capacity > capacity {
+        return;
+    }
+
+    let mut control = VecDeque::new();
+    let mut test = Elastic::new(min_capacity, capacity);
+
+    for operation in operations {
+        match operation {
+            Operation::Enqueue(item) => {
+                let control_result = if control.len() >= capacity {
+                    Some(item)
+                } else {
+                    control.push_back(item.clone());
+                    None
+                };
+                let test_result = test.enqueue(item.clone());
+                assert_eq!(test_result, control_result);
+            }
+            Operation::Dequeue => {
+                let control_result = control.pop_front();
+                let test_result = test.dequeue();
+                assert_eq!(test_result, control_result);
+            }
+        }
+
+        assert!(test.current_capacity() >= min_capacity);
+        assert!(test.current_capacity() <= capacity);
+    }
+});
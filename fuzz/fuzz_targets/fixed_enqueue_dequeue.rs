@@ -43,5 +43,7 @@ fuzz_target!(|data: (Vec<Operation<u8>>, usize)| {
                 assert_eq!(test_result, control_result);
             }
         }
+
+        test.debug_validate();
     }
 });
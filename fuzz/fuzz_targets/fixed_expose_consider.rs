@@ -0,0 +1,89 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+
+use std::collections::VecDeque;
+
+use ufotofu_queues::Fixed;
+use ufotofu_queues::Queue;
+
+#[derive(Debug, Arbitrary)]
+enum Operation<T> {
+    Enqueue(T),
+    Dequeue,
+    // Write the items into the exposed slots, and commit at most the given number of them.
+    ExposeSlots(Vec<T>, u8),
+    // Consume at most the given number of the exposed items.
+    ExposeItems(u8),
+}
+
+fuzz_target!(|data: (Vec<Operation<u8>>, usize)| {
+    let operations = data.0;
+    let capacity = data.1;
+
+    // Restrict capacity to between 1 and 2048 bytes (inclusive).
+    if capacity < 1 || capacity > 2048 {
+        return;
+    }
+
+    let mut control = VecDeque::new();
+    let mut test = Fixed::new(capacity);
+
+    for operation in operations {
+        match operation {
+            Operation::Enqueue(item) => {
+                let control_result = if control.len() >= capacity {
+                    Some(item)
+                } else {
+                    control.push_back(item.clone());
+                    None
+                };
+                let test_result = test.enqueue(item.clone());
+                assert_eq!(test_result, control_result);
+            }
+            Operation::Dequeue => {
+                let control_result = control.pop_front();
+                let test_result = test.dequeue();
+                assert_eq!(test_result, control_result);
+            }
+            Operation::ExposeSlots(items, commit) => match test.expose_slots() {
+                None => assert_eq!(control.len(), capacity),
+                Some(slots) => {
+                    assert!(!slots.is_empty());
+                    assert!(slots.len() <= capacity - control.len());
+
+                    let amount = std::cmp::min(
+                        std::cmp::min(slots.len(), items.len()),
+                        commit as usize,
+                    );
+                    for (slot, item) in slots.iter_mut().zip(&items[..amount]) {
+                        slot.write(*item);
+                    }
+
+                    unsafe {
+                        test.consider_enqueued(amount);
+                    }
+                    control.extend(&items[..amount]);
+                }
+            },
+            Operation::ExposeItems(consume) => match test.expose_items() {
+                None => assert!(control.is_empty()),
+                Some(items) => {
+                    assert!(!items.is_empty());
+                    assert!(items.len() <= control.len());
+
+                    let amount = std::cmp::min(items.len(), consume as usize);
+                    for item in &items[..amount] {
+                        assert_eq!(Some(*item), control.pop_front());
+                    }
+
+                    test.consider_dequeued(amount);
+                }
+            },
+        }
+
+        assert_eq!(test.len(), control.len());
+        test.debug_validate();
+    }
+});
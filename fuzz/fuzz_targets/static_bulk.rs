@@ -41,6 +41,8 @@ fuzz_target!(|data: Vec<Operation<u8>>| {
             }
             Operation::BulkEnqueue(items) => {
                 let amount = test.bulk_enqueue(&items);
+                // Bulk enqueueing fills all free slots, across the end of the ring-buffer.
+                assert_eq!(amount, std::cmp::min(items.len(), 42 - control.len()));
                 for (count, item) in items.iter().enumerate() {
                     if count >= amount {
                         break;
@@ -57,6 +59,8 @@ fuzz_target!(|data: Vec<Operation<u8>>| {
                     test_buffer.resize(n, 0_u8);
 
                     let test_amount = test.bulk_dequeue(&mut test_buffer);
+                    // Bulk dequeueing reads all items that fit, across the end of the ring-buffer.
+                    assert_eq!(test_amount, std::cmp::min(n, control.len()));
                     for _ in 0..test_amount {
                         if let Some(item) = control.pop_front() {
                             control_buffer.push(item.clone());
@@ -67,5 +71,7 @@ fuzz_target!(|data: Vec<Operation<u8>>| {
                 }
             }
         }
+
+        test.debug_validate();
     }
 });
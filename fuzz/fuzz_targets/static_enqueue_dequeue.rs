@@ -37,5 +37,7 @@ fuzz_target!(|data: Vec<Operation<u8>>| {
                 assert_eq!(test_result, control_result);
             }
         }
+
+        test.debug_validate();
     }
 });
@@ -0,0 +1,191 @@
+//! The boxed slices of possibly uninitialised memory that back [`Fixed`](crate::Fixed) and
+//! [`OwningFixed`](crate::OwningFixed).
+//!
+//! With the `nightly` feature, these are plain boxed slices that are generic over an
+//! [`Allocator`](alloc::alloc::Allocator). Without it, the unstable allocator API is not
+//! available, so this module provides a stand-in [`Allocator`] trait whose only implementor is
+//! the global allocator, and allocates everything with it.
+
+extern crate alloc;
+
+#[cfg(feature = "nightly")]
+pub(crate) use nightly::{
+    allocator, from_box, new_uninit_in, new_zeroed_in, try_new_uninit_in, Slots,
+};
+#[cfg(feature = "nightly")]
+pub use nightly::{Allocator, Global};
+
+#[cfg(not(feature = "nightly"))]
+pub(crate) use stable::{
+    allocator, from_box, new_uninit_in, new_zeroed_in, try_new_uninit_in, Slots,
+};
+#[cfg(not(feature = "nightly"))]
+pub use stable::{Allocator, Global};
+
+#[cfg(feature = "nightly")]
+mod nightly {
+    use alloc::boxed::Box;
+
+    use core::mem::MaybeUninit;
+
+    pub use alloc::alloc::{Allocator, Global};
+
+    /// A boxed slice of possibly uninitialised memory, allocated with `A`.
+    pub type Slots<T, A> = Box<[MaybeUninit<T>], A>;
+
+    /// Allocate `capacity` many uninitialised slots with `alloc`. Panic if the allocation fails.
+    pub fn new_uninit_in<T, A: Allocator>(capacity: usize, alloc: A) -> Slots<T, A> {
+        Box::new_uninit_slice_in(capacity, alloc)
+    }
+
+    /// Allocate `capacity` many zeroed slots with `alloc`. Panic if the allocation fails.
+    pub fn new_zeroed_in<T, A: Allocator>(capacity: usize, alloc: A) -> Slots<T, A> {
+        Box::new_zeroed_slice_in(capacity, alloc)
+    }
+
+    /// Allocate `capacity` many uninitialised slots with `alloc`, or return `None` if the
+    /// allocation fails.
+    pub fn try_new_uninit_in<T, A: Allocator>(capacity: usize, alloc: A) -> Option<Slots<T, A>> {
+        Box::try_new_uninit_slice_in(capacity, alloc).ok()
+    }
+
+    /// Return the allocator of some slots.
+    pub fn allocator<T, A: Allocator>(slots: &Slots<T, A>) -> &A {
+        Box::allocator(slots)
+    }
+
+    /// Take ownership of a boxed slice that was allocated with the global allocator.
+    pub fn from_box<T>(data: Box<[MaybeUninit<T>]>) -> Slots<T, Global> {
+        data
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+mod stable {
+    use alloc::alloc::Layout;
+    use alloc::boxed::Box;
+
+    use core::mem::MaybeUninit;
+    use core::ops::{Deref, DerefMut};
+    use core::ptr;
+
+    mod sealed {
+        pub trait Sealed {}
+    }
+
+    /// Stand-in for the unstable [`Allocator`](https://doc.rust-lang.org/alloc/alloc/trait.Allocator.html)
+    /// trait, used when the `nightly` feature is disabled. It is sealed: its only implementor is
+    /// [`Global`], so [`Fixed`](crate::Fixed) and [`OwningFixed`](crate::OwningFixed) always use
+    /// the global allocator.
+    pub trait Allocator: sealed::Sealed {}
+
+    /// The global memory allocator. Stand-in for the unstable
+    /// [`Global`](https://doc.rust-lang.org/alloc/alloc/struct.Global.html) allocator, used when
+    /// the `nightly` feature is disabled.
+    #[derive(Copy, Clone, Default, Debug)]
+    pub struct Global;
+
+    impl sealed::Sealed for Global {}
+    impl Allocator for Global {}
+
+    /// A boxed slice of possibly uninitialised memory. Since [`Global`] is the only
+    /// [`Allocator`], the memory always comes from the global allocator, and `A` is merely
+    /// stored alongside it.
+    pub struct Slots<T, A> {
+        data: Box<[MaybeUninit<T>]>,
+        alloc: A,
+    }
+
+    impl<T, A> Deref for Slots<T, A> {
+        type Target = [MaybeUninit<T>];
+
+        fn deref(&self) -> &[MaybeUninit<T>] {
+            &self.data
+        }
+    }
+
+    impl<T, A> DerefMut for Slots<T, A> {
+        fn deref_mut(&mut self) -> &mut [MaybeUninit<T>] {
+            &mut self.data
+        }
+    }
+
+    pub fn new_uninit_in<T, A: Allocator>(capacity: usize, alloc: A) -> Slots<T, A> {
+        Slots {
+            data: Box::new_uninit_slice(capacity),
+            alloc,
+        }
+    }
+
+    pub fn new_zeroed_in<T, A: Allocator>(capacity: usize, alloc: A) -> Slots<T, A> {
+        Slots {
+            data: Box::new_zeroed_slice(capacity),
+            alloc,
+        }
+    }
+
+    pub fn try_new_uninit_in<T, A: Allocator>(capacity: usize, alloc: A) -> Option<Slots<T, A>> {
+        let layout = Layout::array::<T>(capacity).ok()?;
+
+        let data = if layout.size() == 0 {
+            // Nothing to allocate, this does not touch the allocator.
+            Box::new_uninit_slice(capacity)
+        } else {
+            // The layout has a non-zero size, as `alloc` requires.
+            let memory = unsafe { alloc::alloc::alloc(layout) } as *mut MaybeUninit<T>;
+            if memory.is_null() {
+                return None;
+            }
+
+            // The memory was allocated by the global allocator with the layout of a slice of
+            // `capacity` many `T`, which is also the layout of a slice of `MaybeUninit<T>`.
+            unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(memory, capacity)) }
+        };
+
+        Some(Slots { data, alloc })
+    }
+
+    pub fn allocator<T, A: Allocator>(slots: &Slots<T, A>) -> &A {
+        &slots.alloc
+    }
+
+    pub fn from_box<T>(data: Box<[MaybeUninit<T>]>) -> Slots<T, Global> {
+        Slots {
+            data,
+            alloc: Global,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::MaybeUninit;
+
+    use super::*;
+
+    #[test]
+    fn try_new_uninit_in_returns_none_when_the_layout_overflows() {
+        assert!(try_new_uninit_in::<u16, _>(usize::MAX / 2, Global).is_none());
+    }
+
+    #[test]
+    fn try_new_uninit_in_handles_zero_sized_slots() {
+        assert_eq!(try_new_uninit_in::<u8, _>(0, Global).unwrap().len(), 0);
+        assert_eq!(
+            try_new_uninit_in::<(), _>(usize::MAX, Global)
+                .unwrap()
+                .len(),
+            usize::MAX
+        );
+    }
+
+    #[test]
+    fn slots_keep_the_allocator_and_the_contents_of_boxed_slices() {
+        let slots = new_zeroed_in::<u8, _>(3, Global);
+        assert!(slots.iter().all(|slot| unsafe { slot.assume_init() } == 0));
+
+        let slots = from_box(alloc::vec![MaybeUninit::new(7u8)].into_boxed_slice());
+        assert_eq!(unsafe { slots[0].assume_init() }, 7);
+        let _: &Global = allocator(&slots);
+    }
+}
@@ -0,0 +1,317 @@
+use core::fmt;
+use core::mem::MaybeUninit;
+
+use crate::{Fixed, Queue, QueueFull, SlotWriter, Static};
+
+/// A queue that stores its items inline in a [`Static`] queue if a capacity of at most `N` is
+/// requested, and in a heap-allocated [`Fixed`] queue otherwise. This hides the choice of storage
+/// behind a single type, for code that wants to avoid allocations for small queues.
+///
+/// Use the methods of the [Queue] trait implementation to interact with the contents of the queue;
+/// they dispatch to the active variant.
+pub enum AnyQueue<T, const N: usize> {
+    /// Inline storage, with a capacity of exactly `N`.
+    Small(Static<T, N>),
+    /// Heap-allocated storage.
+    Large(Fixed<T>),
+}
+
+impl<T, const N: usize> AnyQueue<T, N> {
+    /// Create a queue with a capacity of at least `capacity`. Uses a [`Static`] queue of
+    /// capacity `N` if `capacity <= N`, and a [`Fixed`] queue of capacity `capacity` otherwise.
+    /// Panic if the memory allocation for the latter fails.
+    pub fn new(capacity: usize) -> Self {
+        if capacity <= N {
+            AnyQueue::Small(Static::new())
+        } else {
+            AnyQueue::Large(Fixed::new(capacity))
+        }
+    }
+
+    /// Return whether the items are stored inline, in a [`Static`] queue.
+    pub fn is_small(&self) -> bool {
+        matches!(self, AnyQueue::Small(_))
+    }
+}
+
+impl<T: Copy, const N: usize> Queue for AnyQueue<T, N> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        match self {
+            AnyQueue::Small(q) => q.len(),
+            AnyQueue::Large(q) => q.len(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            AnyQueue::Small(q) => q.is_empty(),
+            AnyQueue::Large(q) => q.is_empty(),
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        match self {
+            AnyQueue::Small(q) => q.capacity(),
+            AnyQueue::Large(q) => q.capacity(),
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        match self {
+            AnyQueue::Small(q) => q.remaining(),
+            AnyQueue::Large(q) => q.remaining(),
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        match self {
+            AnyQueue::Small(q) => q.is_full(),
+            AnyQueue::Large(q) => q.is_full(),
+        }
+    }
+
+    fn enqueue(&mut self, item: Self::Item) -> Option<Self::Item> {
+        match self {
+            AnyQueue::Small(q) => q.enqueue(item),
+            AnyQueue::Large(q) => q.enqueue(item),
+        }
+    }
+
+    fn try_enqueue(&mut self, item: Self::Item) -> Result<(), QueueFull<Self::Item>> {
+        match self {
+            AnyQueue::Small(q) => q.try_enqueue(item),
+            AnyQueue::Large(q) => q.try_enqueue(item),
+        }
+    }
+
+    fn enqueue_evicting(&mut self, item: Self::Item) -> Option<Self::Item> {
+        match self {
+            AnyQueue::Small(q) => q.enqueue_evicting(item),
+            AnyQueue::Large(q) => q.enqueue_evicting(item),
+        }
+    }
+
+    fn expose_slots(&mut self) -> Option<&mut [MaybeUninit<Self::Item>]> {
+        match self {
+            AnyQueue::Small(q) => q.expose_slots(),
+            AnyQueue::Large(q) => q.expose_slots(),
+        }
+    }
+
+    unsafe fn consider_enqueued(&mut self, amount: usize) {
+        match self {
+            AnyQueue::Small(q) => unsafe { q.consider_enqueued(amount) },
+            AnyQueue::Large(q) => unsafe { q.consider_enqueued(amount) },
+        }
+    }
+
+    fn enqueue_slots_with<F: FnOnce(&mut SlotWriter<'_, Self::Item>)>(&mut self, f: F) -> usize {
+        match self {
+            AnyQueue::Small(q) => q.enqueue_slots_with(f),
+            AnyQueue::Large(q) => q.enqueue_slots_with(f),
+        }
+    }
+
+    fn bulk_enqueue(&mut self, buffer: &[Self::Item]) -> usize {
+        match self {
+            AnyQueue::Small(q) => q.bulk_enqueue(buffer),
+            AnyQueue::Large(q) => q.bulk_enqueue(buffer),
+        }
+    }
+
+    fn bulk_enqueue_remainder<'a>(&mut self, buffer: &'a [Self::Item]) -> &'a [Self::Item] {
+        match self {
+            AnyQueue::Small(q) => q.bulk_enqueue_remainder(buffer),
+            AnyQueue::Large(q) => q.bulk_enqueue_remainder(buffer),
+        }
+    }
+
+    fn bulk_enqueue_all_or_none(&mut self, buffer: &[Self::Item]) -> bool {
+        match self {
+            AnyQueue::Small(q) => q.bulk_enqueue_all_or_none(buffer),
+            AnyQueue::Large(q) => q.bulk_enqueue_all_or_none(buffer),
+        }
+    }
+
+    fn bulk_enqueue_vectored(&mut self, bufs: &[&[Self::Item]]) -> usize {
+        match self {
+            AnyQueue::Small(q) => q.bulk_enqueue_vectored(bufs),
+            AnyQueue::Large(q) => q.bulk_enqueue_vectored(bufs),
+        }
+    }
+
+    fn fill_with_pattern(&mut self, pattern: &[Self::Item]) -> usize {
+        match self {
+            AnyQueue::Small(q) => q.fill_with_pattern(pattern),
+            AnyQueue::Large(q) => q.fill_with_pattern(pattern),
+        }
+    }
+
+    fn bulk_enqueue_from<P: Queue<Item = Self::Item>>(&mut self, other: &mut P) -> usize {
+        match self {
+            AnyQueue::Small(q) => q.bulk_enqueue_from(other),
+            AnyQueue::Large(q) => q.bulk_enqueue_from(other),
+        }
+    }
+
+    fn enqueue_from_iter<I: Iterator<Item = Self::Item>>(&mut self, iter: &mut I) -> usize {
+        match self {
+            AnyQueue::Small(q) => q.enqueue_from_iter(iter),
+            AnyQueue::Large(q) => q.enqueue_from_iter(iter),
+        }
+    }
+
+    fn dequeue(&mut self) -> Option<Self::Item> {
+        match self {
+            AnyQueue::Small(q) => q.dequeue(),
+            AnyQueue::Large(q) => q.dequeue(),
+        }
+    }
+
+    fn peek(&self) -> Option<Self::Item> {
+        match self {
+            AnyQueue::Small(q) => q.peek(),
+            AnyQueue::Large(q) => q.peek(),
+        }
+    }
+
+    fn last(&self) -> Option<Self::Item> {
+        match self {
+            AnyQueue::Small(q) => q.last(),
+            AnyQueue::Large(q) => q.last(),
+        }
+    }
+
+    fn expose_items(&mut self) -> Option<&[Self::Item]> {
+        match self {
+            AnyQueue::Small(q) => q.expose_items(),
+            AnyQueue::Large(q) => q.expose_items(),
+        }
+    }
+
+    fn consider_dequeued(&mut self, amount: usize) {
+        match self {
+            AnyQueue::Small(q) => q.consider_dequeued(amount),
+            AnyQueue::Large(q) => q.consider_dequeued(amount),
+        }
+    }
+
+    fn dequeue_items_with<R, F: FnOnce(&[Self::Item]) -> (usize, R)>(&mut self, f: F) -> Option<R> {
+        match self {
+            AnyQueue::Small(q) => q.dequeue_items_with(f),
+            AnyQueue::Large(q) => q.dequeue_items_with(f),
+        }
+    }
+
+    fn dequeue_while<F: FnMut(&Self::Item) -> bool>(&mut self, pred: F) -> usize {
+        match self {
+            AnyQueue::Small(q) => q.dequeue_while(pred),
+            AnyQueue::Large(q) => q.dequeue_while(pred),
+        }
+    }
+
+    fn bulk_dequeue(&mut self, buffer: &mut [Self::Item]) -> usize {
+        match self {
+            AnyQueue::Small(q) => q.bulk_dequeue(buffer),
+            AnyQueue::Large(q) => q.bulk_dequeue(buffer),
+        }
+    }
+
+    fn bulk_dequeue_exact(&mut self, buffer: &mut [Self::Item]) -> bool {
+        match self {
+            AnyQueue::Small(q) => q.bulk_dequeue_exact(buffer),
+            AnyQueue::Large(q) => q.bulk_dequeue_exact(buffer),
+        }
+    }
+
+    fn bulk_dequeue_vectored(&mut self, bufs: &mut [&mut [Self::Item]]) -> usize {
+        match self {
+            AnyQueue::Small(q) => q.bulk_dequeue_vectored(bufs),
+            AnyQueue::Large(q) => q.bulk_dequeue_vectored(bufs),
+        }
+    }
+
+    fn bulk_dequeue_uninit(&mut self, buffer: &mut [MaybeUninit<Self::Item>]) -> usize {
+        match self {
+            AnyQueue::Small(q) => q.bulk_dequeue_uninit(buffer),
+            AnyQueue::Large(q) => q.bulk_dequeue_uninit(buffer),
+        }
+    }
+
+    fn bulk_dequeue_exact_uninit(&mut self, buffer: &mut [MaybeUninit<Self::Item>]) -> bool {
+        match self {
+            AnyQueue::Small(q) => q.bulk_dequeue_exact_uninit(buffer),
+            AnyQueue::Large(q) => q.bulk_dequeue_exact_uninit(buffer),
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for AnyQueue<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnyQueue::Small(q) => f.debug_tuple("Small").field(q).finish(),
+            AnyQueue::Large(q) => f.debug_tuple("Large").field(q).finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+
+    fn exercise<Q: Queue<Item = u8>>(queue: &mut Q) {
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        assert_eq!(
+            queue.bulk_enqueue_remainder(b"tofu"),
+            &b"tofu"[queue.capacity() - 2..]
+        );
+        assert!(queue.is_full());
+        assert_eq!(queue.peek(), Some(b'f'));
+
+        let mut buf = [0; 3];
+        assert!(queue.bulk_dequeue_exact(&mut buf));
+        assert_eq!(&buf, b"fot");
+    }
+
+    #[test]
+    fn picks_static_storage_for_small_capacities() {
+        let mut queue: AnyQueue<u8, 4> = AnyQueue::new(3);
+        assert!(queue.is_small());
+        assert_eq!(queue.capacity(), 4);
+
+        exercise(&mut queue);
+        assert_eq!(queue.dequeue(), Some(b'o'));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn picks_fixed_storage_for_large_capacities() {
+        let mut queue: AnyQueue<u8, 4> = AnyQueue::new(5);
+        assert!(!queue.is_small());
+        assert_eq!(queue.capacity(), 5);
+
+        exercise(&mut queue);
+        assert_eq!(queue.len(), 2);
+    }
+
+    #[test]
+    fn test_debug_impl() {
+        let mut queue: AnyQueue<u8, 2> = AnyQueue::new(2);
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Small(Static { len: 1, data: [7] })"
+        );
+
+        let queue: AnyQueue<u8, 2> = AnyQueue::new(3);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Large(Fixed { capacity: 3, len: 0, data: [] })"
+        );
+    }
+}
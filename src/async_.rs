@@ -0,0 +1,210 @@
+extern crate std;
+
+use std::mem;
+use std::sync::Mutex;
+use std::task::Waker;
+use std::vec::Vec;
+
+use core::fmt;
+use core::future::poll_fn;
+use core::task::Poll;
+
+use crate::{Fixed, Queue};
+
+/// A queue holding up to a certain number of items, whose [`enqueue`](AsyncQueue::enqueue) waits
+/// for a free slot when the queue is full, and whose [`dequeue`](AsyncQueue::dequeue) waits for an
+/// item when the queue is empty. The capacity is set upon creation and remains fixed.
+///
+/// All methods take `&self`, so the queue can be shared between tasks, for example via an
+/// [`Arc`](std::sync::Arc). Gated behind the `async` feature.
+pub struct AsyncQueue<T> {
+    state: Mutex<State<T>>,
+}
+
+struct State<T> {
+    queue: Fixed<T>,
+    /// Wakers of the tasks waiting for a free slot.
+    enqueue_wakers: Vec<Waker>,
+    /// Wakers of the tasks waiting for an item.
+    dequeue_wakers: Vec<Waker>,
+}
+
+/// Add a waker to a list, unless it would wake the same task as a waker already in the list.
+fn register(wakers: &mut Vec<Waker>, waker: &Waker) {
+    if !wakers.iter().any(|registered| registered.will_wake(waker)) {
+        wakers.push(waker.clone());
+    }
+}
+
+fn wake_all(wakers: Vec<Waker>) {
+    for waker in wakers {
+        waker.wake();
+    }
+}
+
+impl<T: Copy> AsyncQueue<T> {
+    /// Create an asynchronous queue. Panic if the initial memory allocation fails.
+    pub fn new(capacity: usize) -> Self {
+        AsyncQueue {
+            state: Mutex::new(State {
+                queue: Fixed::new(capacity),
+                enqueue_wakers: Vec::new(),
+                dequeue_wakers: Vec::new(),
+            }),
+        }
+    }
+
+    /// Return the capacity with which this queue was initialised.
+    pub fn capacity(&self) -> usize {
+        self.state.lock().unwrap().queue.capacity()
+    }
+
+    /// Return the number of items currently in the queue.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().queue.len()
+    }
+
+    /// Return whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Enqueue an item, waiting until the queue has a free slot for it.
+    ///
+    /// Never completes if the queue was created with capacity zero.
+    pub async fn enqueue(&self, item: T) {
+        poll_fn(|cx| {
+            let mut state = self.state.lock().unwrap();
+
+            if state.queue.enqueue(item).is_some() {
+                register(&mut state.enqueue_wakers, cx.waker());
+                Poll::Pending
+            } else {
+                let wakers = mem::take(&mut state.dequeue_wakers);
+                drop(state);
+                wake_all(wakers);
+
+                Poll::Ready(())
+            }
+        })
+        .await
+    }
+
+    /// Dequeue the next item, waiting until the queue contains one.
+    pub async fn dequeue(&self) -> T {
+        poll_fn(|cx| {
+            let mut state = self.state.lock().unwrap();
+
+            match state.queue.dequeue() {
+                None => {
+                    register(&mut state.dequeue_wakers, cx.waker());
+                    Poll::Pending
+                }
+                Some(item) => {
+                    let wakers = mem::take(&mut state.enqueue_wakers);
+                    drop(state);
+                    wake_all(wakers);
+
+                    Poll::Ready(item)
+                }
+            }
+        })
+        .await
+    }
+}
+
+impl<T: Copy + fmt::Debug> fmt::Debug for AsyncQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.lock().unwrap();
+
+        f.debug_struct("AsyncQueue")
+            .field("queue", &state.queue)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::format;
+    use std::sync::Arc;
+    use std::task::{Context, Wake};
+    use std::thread::{self, Thread};
+
+    use core::future::Future;
+    use core::pin::pin;
+
+    use super::*;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// Run a future to completion on the current thread, parking the thread while it is pending.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn enqueues_and_dequeues_without_waiting() {
+        let queue = AsyncQueue::new(2);
+
+        block_on(queue.enqueue(7));
+        block_on(queue.enqueue(21));
+        assert_eq!(queue.len(), 2);
+
+        // Queue should be first-in, first-out.
+        assert_eq!(block_on(queue.dequeue()), 7);
+        assert_eq!(block_on(queue.dequeue()), 21);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn transfers_items_between_tasks() {
+        const ITEMS: u32 = 10_000;
+
+        // A small capacity makes both sides wait for each other frequently.
+        let queue = Arc::new(AsyncQueue::new(3));
+
+        let producer_queue = queue.clone();
+        let producer = thread::spawn(move || {
+            block_on(async {
+                for item in 0..ITEMS {
+                    producer_queue.enqueue(item).await;
+                }
+            })
+        });
+
+        block_on(async {
+            for expected in 0..ITEMS {
+                assert_eq!(queue.dequeue().await, expected);
+            }
+        });
+
+        producer.join().unwrap();
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_debug_impl() {
+        let queue = AsyncQueue::new(2);
+
+        block_on(queue.enqueue(7));
+        assert_eq!(
+            format!("{:?}", queue),
+            "AsyncQueue { queue: Fixed { capacity: 2, len: 1, data: [7] } }"
+        );
+    }
+}
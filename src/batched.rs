@@ -0,0 +1,360 @@
+use core::cmp::min;
+use core::fmt;
+use core::mem::MaybeUninit;
+
+use crate::{Queue, QueueFull, SlotWriter};
+
+/// A queue adaptor that holds back items until an inner queue contains at least a certain number
+/// of them, and then releases them as one batch. Useful when dequeueing has a high per-call
+/// overhead downstream. Enqueueing passes through to the inner queue unchanged.
+///
+/// Once the inner queue holds at least `batch_size` items, all of its items are released, and
+/// can be dequeued by any means. No further items are released until the released items have
+/// been dequeued. While no items are released, all dequeueing methods act as if the queue was
+/// empty, although [`len`](Queue::len) reports the items being held back. Call
+/// [`flush`](Batched::flush) to release a smaller final batch, for example when the producer has
+/// finished.
+pub struct Batched<Q> {
+    inner: Q,
+    batch_size: usize,
+    /// Number of items at the front of the inner queue that may be dequeued.
+    released: usize,
+}
+
+impl<Q: Queue> Batched<Q> {
+    /// Wrap a queue, releasing its items in batches of at least `batch_size` items.
+    pub fn new(inner: Q, batch_size: usize) -> Self {
+        Batched {
+            inner,
+            batch_size,
+            released: 0,
+        }
+    }
+
+    /// Release all items of the inner queue, even if they fall short of the batch size.
+    pub fn flush(&mut self) {
+        self.released = self.inner.len();
+    }
+
+    /// Return the number of items that can currently be dequeued.
+    pub fn released(&self) -> usize {
+        if self.released == 0 && self.inner.len() >= self.batch_size {
+            self.inner.len()
+        } else {
+            self.released
+        }
+    }
+
+    /// Return a reference to the inner queue.
+    pub fn get_ref(&self) -> &Q {
+        &self.inner
+    }
+
+    /// Unwrap the inner queue, discarding which of its items are released.
+    pub fn into_inner(self) -> Q {
+        self.inner
+    }
+
+    /// Release the next batch if there are no released items left and it is large enough, and
+    /// return the number of released items.
+    fn release(&mut self) -> usize {
+        self.released = self.released();
+        self.released
+    }
+}
+
+impl<Q: Queue> Queue for Batched<Q> {
+    type Item = Q::Item;
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    fn remaining(&self) -> usize {
+        self.inner.remaining()
+    }
+
+    fn is_full(&self) -> bool {
+        self.inner.is_full()
+    }
+
+    fn enqueue(&mut self, item: Self::Item) -> Option<Self::Item> {
+        self.inner.enqueue(item)
+    }
+
+    fn try_enqueue(&mut self, item: Self::Item) -> Result<(), QueueFull<Self::Item>> {
+        self.inner.try_enqueue(item)
+    }
+
+    /// Enqueue an item, even if the queue is full. If it is, the oldest item is evicted and
+    /// returned, whether it has been released or not.
+    fn enqueue_evicting(&mut self, item: Self::Item) -> Option<Self::Item> {
+        let evicted = self.inner.enqueue_evicting(item);
+        if evicted.is_some() && self.released > 0 {
+            self.released -= 1;
+        }
+
+        evicted
+    }
+
+    fn expose_slots(&mut self) -> Option<&mut [MaybeUninit<Self::Item>]> {
+        self.inner.expose_slots()
+    }
+
+    unsafe fn consider_enqueued(&mut self, amount: usize) {
+        unsafe { self.inner.consider_enqueued(amount) }
+    }
+
+    fn enqueue_slots_with<F: FnOnce(&mut SlotWriter<'_, Self::Item>)>(&mut self, f: F) -> usize {
+        self.inner.enqueue_slots_with(f)
+    }
+
+    fn bulk_enqueue(&mut self, buffer: &[Self::Item]) -> usize {
+        self.inner.bulk_enqueue(buffer)
+    }
+
+    fn bulk_enqueue_remainder<'a>(&mut self, buffer: &'a [Self::Item]) -> &'a [Self::Item] {
+        self.inner.bulk_enqueue_remainder(buffer)
+    }
+
+    fn bulk_enqueue_all_or_none(&mut self, buffer: &[Self::Item]) -> bool {
+        self.inner.bulk_enqueue_all_or_none(buffer)
+    }
+
+    fn fill_with_pattern(&mut self, pattern: &[Self::Item]) -> usize {
+        self.inner.fill_with_pattern(pattern)
+    }
+
+    fn bulk_enqueue_from<P: Queue<Item = Self::Item>>(&mut self, other: &mut P) -> usize {
+        self.inner.bulk_enqueue_from(other)
+    }
+
+    fn enqueue_from_iter<I: Iterator<Item = Self::Item>>(&mut self, iter: &mut I) -> usize {
+        self.inner.enqueue_from_iter(iter)
+    }
+
+    /// Attempt to dequeue the next item.
+    ///
+    /// Will return `None` if no items are released at the time of calling.
+    fn dequeue(&mut self) -> Option<Self::Item> {
+        if self.release() == 0 {
+            None
+        } else {
+            let item = self.inner.dequeue()?;
+            self.released -= 1;
+
+            Some(item)
+        }
+    }
+
+    /// Return the next item that would be dequeued, without removing it from the queue.
+    ///
+    /// Will return `None` if no items are released at the time of calling.
+    fn peek(&self) -> Option<Self::Item> {
+        if self.released() == 0 {
+            None
+        } else {
+            self.inner.peek()
+        }
+    }
+
+    fn last(&self) -> Option<Self::Item> {
+        self.inner.last()
+    }
+
+    /// Expose a non-empty slice of released items to be dequeued.
+    ///
+    /// Will return `None` if no items are released at the time of calling.
+    fn expose_items(&mut self) -> Option<&[Self::Item]> {
+        let released = self.release();
+        if released == 0 {
+            None
+        } else {
+            let items = self.inner.expose_items()?;
+            let amount = min(released, items.len());
+
+            Some(&items[..amount])
+        }
+    }
+
+    fn consider_dequeued(&mut self, amount: usize) {
+        debug_assert!(
+            amount <= self.released,
+            "cannot consider more items dequeued than were exposed"
+        );
+
+        self.inner.consider_dequeued(amount);
+        self.released = self.released.saturating_sub(amount);
+    }
+
+    /// Dequeue a non-zero number of released items by writing them into a given buffer and
+    /// returning how many items were dequeued.
+    ///
+    /// Will return `0` if no items are released at the time of calling.
+    fn bulk_dequeue(&mut self, buffer: &mut [Self::Item]) -> usize {
+        let amount = min(self.release(), buffer.len());
+        let amount = self.inner.bulk_dequeue(&mut buffer[..amount]);
+        self.released -= amount;
+
+        amount
+    }
+
+    /// Dequeue exactly as many released items as fit into a given buffer, or none at all, and
+    /// return whether the items were dequeued.
+    ///
+    /// Will return `false` and leave both the queue and the buffer unchanged if fewer than
+    /// `buffer.len()` items are released at the time of calling.
+    fn bulk_dequeue_exact(&mut self, buffer: &mut [Self::Item]) -> bool {
+        if self.release() < buffer.len() {
+            false
+        } else {
+            let dequeued = self.inner.bulk_dequeue_exact(buffer);
+            debug_assert!(dequeued, "queue ran out of items despite releasing them");
+            self.released -= buffer.len();
+
+            true
+        }
+    }
+
+    /// Dequeue a non-zero number of released items by writing them into a given buffer of
+    /// possibly uninitialised memory and returning how many items were dequeued.
+    ///
+    /// Will return `0` if no items are released at the time of calling.
+    fn bulk_dequeue_uninit(&mut self, buffer: &mut [MaybeUninit<Self::Item>]) -> usize {
+        let amount = min(self.release(), buffer.len());
+        let amount = self.inner.bulk_dequeue_uninit(&mut buffer[..amount]);
+        self.released -= amount;
+
+        amount
+    }
+
+    /// Dequeue exactly as many released items as fit into a given buffer of possibly
+    /// uninitialised memory, or none at all, and return whether the items were dequeued.
+    ///
+    /// Will return `false` and leave both the queue and the buffer unchanged if fewer than
+    /// `buffer.len()` items are released at the time of calling.
+    fn bulk_dequeue_exact_uninit(&mut self, buffer: &mut [MaybeUninit<Self::Item>]) -> bool {
+        if self.release() < buffer.len() {
+            false
+        } else {
+            let dequeued = self.inner.bulk_dequeue_exact_uninit(buffer);
+            debug_assert!(dequeued, "queue ran out of items despite releasing them");
+            self.released -= buffer.len();
+
+            true
+        }
+    }
+}
+
+impl<Q: fmt::Debug> fmt::Debug for Batched<Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Batched")
+            .field("batch_size", &self.batch_size)
+            .field("released", &self.released)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::format;
+
+    use super::*;
+    use crate::Static;
+
+    #[test]
+    fn holds_back_items_until_batch_is_complete() {
+        let mut queue = Batched::new(Static::<u8, 8>::new(), 3);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2]), 2);
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.released(), 0);
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.peek(), None);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 2]), 0);
+        assert!(queue.expose_items().is_none());
+
+        // Reaching the batch size releases all items at once.
+        assert_eq!(queue.bulk_enqueue(&[3, 4]), 2);
+        assert_eq!(queue.released(), 4);
+        assert_eq!(queue.peek(), Some(1));
+        assert_eq!(queue.dequeue(), Some(1));
+
+        // Items enqueued meanwhile wait for the next batch.
+        assert_eq!(queue.enqueue(5), None);
+        let mut buf = [0; 8];
+        assert_eq!(queue.bulk_dequeue(&mut buf), 3);
+        assert_eq!(buf[..3], [2, 3, 4]);
+        assert_eq!(queue.released(), 0);
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.len(), 1);
+    }
+
+    #[test]
+    fn flush_releases_a_smaller_batch() {
+        let mut queue = Batched::new(Static::<u8, 8>::new(), 3);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2]), 2);
+        assert!(!queue.bulk_dequeue_exact(&mut [0; 2]));
+        assert!(!queue.bulk_dequeue_exact_uninit(&mut [MaybeUninit::uninit(); 2]));
+
+        queue.flush();
+        assert_eq!(queue.released(), 2);
+        assert_eq!(queue.expose_items(), Some(&[1, 2][..]));
+        queue.consider_dequeued(1);
+
+        let mut uninit_buf = [MaybeUninit::uninit(); 4];
+        assert_eq!(queue.bulk_dequeue_uninit(&mut uninit_buf), 1);
+        assert_eq!(unsafe { uninit_buf[0].assume_init() }, 2);
+        assert!(queue.is_empty());
+        assert_eq!(queue.into_inner().len(), 0);
+    }
+
+    #[test]
+    fn evicts_held_back_and_released_items() {
+        let mut queue = Batched::new(Static::<u8, 2>::new(), 3);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2]), 2);
+        assert_eq!(queue.enqueue_evicting(3), Some(1));
+        assert_eq!(queue.released(), 0);
+
+        queue.flush();
+        assert_eq!(queue.enqueue_evicting(4), Some(2));
+        assert_eq!(queue.released(), 1);
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "cannot consider more items dequeued than were exposed")]
+    fn panics_on_considering_more_items_dequeued_than_released() {
+        let mut queue = Batched::new(Static::<u8, 8>::new(), 2);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert_eq!(queue.expose_items(), Some(&[1, 2, 3][..]));
+        queue.consider_dequeued(4);
+    }
+
+    #[test]
+    fn test_debug_impl() {
+        let mut queue = Batched::new(Static::<u8, 2>::new(), 2);
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Batched { batch_size: 2, released: 0, inner: Static { len: 1, data: [7] } }"
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use core::fmt;
+use core::mem::MaybeUninit;
+
+use crate::{DebugItems, Queue};
+
+/// A queue holding up to a certain number of items in a borrowed slice of memory, for example an
+/// array on the stack or a region of an arena. The capacity is the length of that slice, which
+/// needs to be known only at runtime. Performs no allocations.
+///
+/// Use the methods of the [Queue] trait implementation to interact with the contents of the queue.
+pub struct Borrowed<'a, T> {
+    /// Slice of memory, used as a ring-buffer.
+    data: &'a mut [MaybeUninit<T>],
+    /// Read index.
+    read: usize,
+    /// Amount of valid data.
+    amount: usize,
+}
+
+impl<'a, T> Borrowed<'a, T> {
+    /// Create an empty queue that stores its items in the given memory. Any data in that memory
+    /// is ignored, and it may be overwritten by the queue.
+    pub fn new(data: &'a mut [MaybeUninit<T>]) -> Self {
+        Borrowed {
+            data,
+            read: 0,
+            amount: 0,
+        }
+    }
+
+    /// Return the items of the queue as two slices, in FIFO order: the front of the queue
+    /// comes first, and the second slice is empty if the items are stored contiguously.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let head_len = core::cmp::min(self.amount, self.data.len() - self.read);
+        let tail_len = self.amount - head_len;
+
+        unsafe {
+            (
+                self.data[self.read..self.read + head_len].assume_init_ref(),
+                self.data[..tail_len].assume_init_ref(),
+            )
+        }
+    }
+
+    fn is_data_contiguous(&self) -> bool {
+        self.read + self.amount < self.data.len()
+    }
+
+    /// Return a slice containing the next items that should be read.
+    fn readable_slice(&mut self) -> &[MaybeUninit<T>] {
+        if self.is_data_contiguous() {
+            &self.data[self.read..self.write_to()]
+        } else {
+            &self.data[self.read..]
+        }
+    }
+
+    /// Return a slice containing the next slots that should be written to.
+    fn writeable_slice(&mut self) -> &mut [MaybeUninit<T>] {
+        let capacity = self.data.len();
+        let write_to = self.write_to();
+        if self.is_data_contiguous() {
+            &mut self.data[write_to..capacity]
+        } else {
+            &mut self.data[write_to..self.read]
+        }
+    }
+
+    fn write_to(&self) -> usize {
+        if self.data.is_empty() {
+            // There are no slots to write to, and no capacity to take the remainder by.
+            0
+        } else {
+            (self.read + self.amount) % self.data.len()
+        }
+    }
+}
+
+impl<T: Copy> Queue for Borrowed<'_, T> {
+    type Item = T;
+
+    /// Return the number of items in the queue.
+    fn len(&self) -> usize {
+        self.amount
+    }
+
+    /// Return the length of the borrowed memory.
+    fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Attempt to enqueue the next item.
+    ///
+    /// Will return the item if the queue is full at the time of calling.
+    fn enqueue(&mut self, item: T) -> Option<T> {
+        if self.amount == self.data.len() {
+            Some(item)
+        } else {
+            self.data[self.write_to()].write(item);
+            self.amount += 1;
+
+            None
+        }
+    }
+
+    /// Expose a non-empty slice of memory for the client code to fill with items that should
+    /// be enqueued.
+    ///
+    /// Will return `None` if the queue is full at the time of calling.
+    fn expose_slots(&mut self) -> Option<&mut [MaybeUninit<T>]> {
+        if self.amount == self.data.len() {
+            None
+        } else {
+            Some(self.writeable_slice())
+        }
+    }
+
+    /// Inform the queue that `amount` many items have been written to the first `amount`
+    /// indices of the `expose_slots` it has most recently exposed.
+    ///
+    /// #### Invariants
+    ///
+    /// Callers must have written into (at least) the `amount` many first `expose_slots` that
+    /// were most recently exposed. Failure to uphold this invariant may cause undefined behavior.
+    ///
+    /// #### Safety
+    ///
+    /// The queue will assume the first `amount` many `expose_slots` that were most recently
+    /// exposed to contain initialized memory after this call, even if the memory it exposed was
+    /// originally uninitialized. Violating the invariants will cause the queue to read undefined
+    /// memory, which triggers undefined behavior.
+    unsafe fn consider_enqueued(&mut self, amount: usize) {
+        debug_assert!(
+            self.amount + amount <= self.data.len(),
+            "cannot consider more items enqueued than the queue has slots"
+        );
+
+        self.amount += amount;
+    }
+
+    /// Attempt to dequeue the next item.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn dequeue(&mut self) -> Option<T> {
+        if self.amount == 0 {
+            None
+        } else {
+            let previous_read = self.read;
+            // Advance the read index by 1 or reset to 0 if at capacity.
+            self.read = (self.read + 1) % self.data.len();
+            self.amount -= 1;
+
+            Some(unsafe { self.data[previous_read].assume_init() })
+        }
+    }
+
+    /// Return the next item that would be dequeued, without removing it from the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn peek(&self) -> Option<T> {
+        if self.amount == 0 {
+            None
+        } else {
+            Some(unsafe { self.data[self.read].assume_init() })
+        }
+    }
+
+    /// Return the most recently enqueued item, without removing it from the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn last(&self) -> Option<T> {
+        if self.amount == 0 {
+            None
+        } else {
+            Some(unsafe {
+                self.data[(self.read + self.amount - 1) % self.data.len()].assume_init()
+            })
+        }
+    }
+
+    /// Expose a non-empty slice of items to be dequeued.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn expose_items(&mut self) -> Option<&[T]> {
+        if self.amount == 0 {
+            None
+        } else {
+            Some(unsafe { self.readable_slice().assume_init_ref() })
+        }
+    }
+
+    /// Mark `amount` many items as having been dequeued.
+    ///
+    /// #### Invariants
+    ///
+    /// Callers must not mark items as dequeued that had not previously been exposed by
+    /// `expose_items`.
+    fn consider_dequeued(&mut self, amount: usize) {
+        debug_assert!(
+            amount <= self.amount,
+            "cannot consider more items dequeued than the queue contains"
+        );
+
+        if amount > 0 {
+            self.read = (self.read + amount) % self.data.len();
+            self.amount -= amount;
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Borrowed<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (head, tail) = self.as_slices();
+
+        f.debug_struct("Borrowed")
+            .field("capacity", &self.data.len())
+            .field("len", &self.amount)
+            .field("data", &DebugItems(head, tail))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn enqueues_and_dequeues_with_correct_amount() {
+        let mut memory = [MaybeUninit::uninit(); 4];
+        let mut queue = Borrowed::new(&mut memory);
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.enqueue(21), None);
+        assert_eq!(queue.enqueue(196), None);
+        assert_eq!(queue.len(), 3);
+
+        assert_eq!(queue.enqueue(233), None);
+        assert_eq!(queue.len(), 4);
+        assert!(queue.is_full());
+
+        // Queue should be first-in, first-out.
+        assert_eq!(queue.dequeue(), Some(7));
+        assert_eq!(queue.len(), 3);
+    }
+
+    #[test]
+    fn returns_item_on_enqueue_when_queue_is_full() {
+        let mut memory = [MaybeUninit::uninit(); 1];
+        let mut queue = Borrowed::new(&mut memory);
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.enqueue(0), Some(0));
+        assert_eq!(queue.len(), 1);
+    }
+
+    #[test]
+    fn returns_none_on_dequeue_when_queue_is_empty() {
+        let mut memory = [MaybeUninit::uninit(); 1];
+        let mut queue: Borrowed<u8> = Borrowed::new(&mut memory);
+
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.peek(), None);
+        assert_eq!(queue.last(), None);
+        assert!(queue.expose_items().is_none());
+    }
+
+    #[test]
+    fn bulk_enqueues_and_dequeues_across_the_end_of_the_ring_buffer() {
+        let mut memory = [MaybeUninit::uninit(); 4];
+        let mut queue = Borrowed::new(&mut memory);
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        assert_eq!(queue.dequeue(), Some(b'f'));
+
+        // Filling the queue wraps around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue_remainder(b"tofu"), b"u");
+        assert!(queue.expose_slots().is_none());
+        assert_eq!(queue.peek(), Some(b'o'));
+        assert_eq!(queue.last(), Some(b'f'));
+
+        let mut buf = [0; 4];
+        assert!(queue.bulk_dequeue_exact(&mut buf));
+        assert_eq!(&buf, b"otof");
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn capacity_zero_rejects_everything() {
+        let mut memory: [MaybeUninit<u8>; 0] = [];
+        let mut queue = Borrowed::new(&mut memory);
+
+        assert_eq!(queue.capacity(), 0);
+        assert_eq!(queue.enqueue(1), Some(1));
+        assert_eq!(queue.bulk_enqueue(&[1]), 0);
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.bulk_dequeue(&mut [0]), 0);
+        queue.consider_dequeued(0);
+    }
+
+    #[test]
+    fn test_debug_impl() {
+        let mut memory = [MaybeUninit::uninit(); 3];
+        let mut queue = Borrowed::new(&mut memory);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.enqueue(4), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Borrowed { capacity: 3, len: 3, data: [2, 3, 4] }"
+        );
+    }
+}
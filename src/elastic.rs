@@ -0,0 +1,764 @@
+extern crate alloc;
+
+use alloc::boxed::Box;
+
+use core::fmt;
+use core::mem::MaybeUninit;
+
+use crate::{DebugItems, Queue};
+
+/// A queue whose capacity grows and shrinks between a minimum and a maximum capacity.
+///
+/// The queue starts out with its minimum capacity. When enqueueing into a full queue, its
+/// capacity doubles (but never exceeds the maximum capacity); [`Elastic::with_growth_factor`]
+/// configures a different factor. When the number of items drops
+/// below a quarter of the capacity after dequeueing, the capacity halves (but never drops
+/// below the minimum capacity); [`Elastic::with_shrink_policy`] configures when to shrink
+/// instead. Every change of capacity performs a single heap allocation and moves the items into
+/// the new allocation.
+///
+/// Use the methods of the [Queue] trait implementation to interact with the contents of the queue.
+pub struct Elastic<T> {
+    /// Slice of memory, used as a ring-buffer.
+    data: Box<[MaybeUninit<T>]>,
+    /// Read index.
+    read: usize,
+    /// Amount of valid data.
+    amount: usize,
+    /// The capacity never drops below this.
+    min_capacity: usize,
+    /// The capacity never exceeds this.
+    max_capacity: usize,
+    /// When to halve the capacity after dequeueing.
+    shrink_policy: ShrinkPolicy,
+    /// The factor by which the capacity grows when enqueueing into a full queue.
+    growth_factor: f32,
+}
+
+/// Determines when an [`Elastic`] queue halves its capacity after dequeueing. The capacity never
+/// drops below the minimum capacity of the queue, regardless of the policy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShrinkPolicy {
+    /// Never shrink, so that the capacity only ever grows. Avoids reallocations for bursty
+    /// workloads, at the cost of memory.
+    Never,
+    /// Shrink as soon as the items fit into half of the capacity.
+    Eager,
+    /// Shrink when the number of items drops below the given fraction of the capacity. The
+    /// default is `WhenBelow(0.25)`. The fraction must lie in `(0.0, 1.0]`. With a fraction above
+    /// one half, the queue still waits until its items fit into half of the capacity.
+    WhenBelow(f32),
+}
+
+impl Default for ShrinkPolicy {
+    fn default() -> Self {
+        ShrinkPolicy::WhenBelow(0.25)
+    }
+}
+
+impl<T> Elastic<T> {
+    /// Create an elastic queue whose capacity stays between `min_capacity` and `max_capacity`
+    /// (both inclusive). The queue starts out with a capacity of `min_capacity`.
+    ///
+    /// Panic if `min_capacity` is zero, if `min_capacity` is greater than `max_capacity`, or if
+    /// the initial memory allocation fails.
+    pub fn new(min_capacity: usize, max_capacity: usize) -> Self {
+        assert!(
+            min_capacity > 0,
+            "the minimum capacity of an Elastic queue must be non-zero"
+        );
+        assert!(
+            min_capacity <= max_capacity,
+            "the minimum capacity of an Elastic queue must not exceed its maximum capacity"
+        );
+
+        Elastic {
+            data: Box::new_uninit_slice(min_capacity),
+            read: 0,
+            amount: 0,
+            min_capacity,
+            max_capacity,
+            shrink_policy: ShrinkPolicy::default(),
+            growth_factor: 2.0,
+        }
+    }
+
+    /// Set the factor by which the capacity grows when enqueueing into a full queue; the default
+    /// is `2.0`. The grown capacity is rounded down, but always exceeds the previous capacity by
+    /// at least one slot.
+    ///
+    /// Panic if `growth_factor` is not greater than `1.0` (this includes NaN), since the queue
+    /// would otherwise grow one slot at a time and copy its items on almost every enqueue.
+    pub fn with_growth_factor(mut self, growth_factor: f32) -> Self {
+        assert!(
+            growth_factor > 1.0,
+            "the growth factor of an Elastic queue must be greater than 1.0"
+        );
+
+        self.growth_factor = growth_factor;
+        self
+    }
+
+    /// Return the factor by which the capacity grows when enqueueing into a full queue.
+    pub fn growth_factor(&self) -> f32 {
+        self.growth_factor
+    }
+
+    /// Set when the queue halves its capacity after dequeueing.
+    ///
+    /// Panic if the policy is [`ShrinkPolicy::WhenBelow`] with a fraction that does not lie in
+    /// `(0.0, 1.0]` (this includes NaN), since the queue would otherwise silently never shrink.
+    pub fn with_shrink_policy(mut self, shrink_policy: ShrinkPolicy) -> Self {
+        if let ShrinkPolicy::WhenBelow(ratio) = shrink_policy {
+            assert!(
+                ratio > 0.0 && ratio <= 1.0,
+                "the shrink ratio of an Elastic queue must lie in (0.0, 1.0]"
+            );
+        }
+
+        self.shrink_policy = shrink_policy;
+        self
+    }
+
+    /// Return when the queue halves its capacity after dequeueing.
+    pub fn shrink_policy(&self) -> ShrinkPolicy {
+        self.shrink_policy
+    }
+
+    /// Return the current capacity of this queue.
+    ///
+    /// The number of free item slots before the queue has to grow is `q.current_capacity() - q.len()`.
+    pub fn current_capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Return the capacity below which this queue never shrinks.
+    pub fn min_capacity(&self) -> usize {
+        self.min_capacity
+    }
+
+    /// Return the capacity beyond which this queue never grows.
+    pub fn max_capacity(&self) -> usize {
+        self.max_capacity
+    }
+
+    fn is_data_contiguous(&self) -> bool {
+        self.read + self.amount < self.current_capacity()
+    }
+
+    /// Return a slice containing the next items that should be read.
+    fn readable_slice(&mut self) -> &[MaybeUninit<T>] {
+        if self.is_data_contiguous() {
+            &self.data[self.read..self.write_to()]
+        } else {
+            &self.data[self.read..]
+        }
+    }
+
+    /// Return a slice containing the next slots that should be written to.
+    fn writeable_slice(&mut self) -> &mut [MaybeUninit<T>] {
+        let capacity = self.current_capacity();
+        let write_to = self.write_to();
+        if self.is_data_contiguous() {
+            &mut self.data[write_to..capacity]
+        } else {
+            &mut self.data[write_to..self.read]
+        }
+    }
+
+    /// Return the two slices of memory that hold the items of the queue, in FIFO order. The
+    /// second slice is empty unless the items wrap around the end of the ring-buffer.
+    fn live_slices(&self) -> (&[MaybeUninit<T>], &[MaybeUninit<T>]) {
+        let head_len = core::cmp::min(self.amount, self.current_capacity() - self.read);
+        let tail_len = self.amount - head_len;
+
+        (
+            &self.data[self.read..self.read + head_len],
+            &self.data[..tail_len],
+        )
+    }
+
+    fn write_to(&self) -> usize {
+        (self.read + self.amount) % self.current_capacity()
+    }
+}
+
+impl<T: Copy> Elastic<T> {
+    /// Grow the capacity by the growth factor (but by at least one slot), unless that would exceed
+    /// the maximum capacity. Return whether the capacity changed.
+    fn grow(&mut self) -> bool {
+        let capacity = self.current_capacity();
+
+        if capacity == self.max_capacity {
+            false
+        } else {
+            // Converting to `usize` rounds down, and saturates on overflow.
+            let grown = (capacity as f64 * self.growth_factor as f64) as usize;
+            self.set_capacity(core::cmp::min(
+                core::cmp::max(grown, capacity + 1),
+                self.max_capacity,
+            ));
+            true
+        }
+    }
+
+    /// Halve the capacity (but not below the minimum capacity) if the shrink policy says so.
+    fn shrink_if_sparse(&mut self) {
+        let capacity = self.current_capacity();
+
+        let sparse = match self.shrink_policy {
+            ShrinkPolicy::Never => false,
+            ShrinkPolicy::Eager => self.amount <= capacity / 2,
+            ShrinkPolicy::WhenBelow(ratio) => self.amount < (ratio * capacity as f32) as usize,
+        };
+
+        // A ratio above one half may consider the queue sparse while its items do not yet fit
+        // into the halved capacity.
+        let halved = core::cmp::max(capacity / 2, self.min_capacity);
+        if capacity > self.min_capacity && sparse && halved >= self.amount {
+            self.set_capacity(halved);
+        }
+    }
+
+    /// Move all items into a fresh allocation of the given capacity, such that the item to be
+    /// read next is located at index zero. The new capacity must be at least `self.amount`.
+    fn set_capacity(&mut self, new_capacity: usize) {
+        debug_assert!(
+            new_capacity >= self.amount,
+            "cannot move the items of a queue into a smaller capacity than their amount"
+        );
+
+        let mut new_data = Box::new_uninit_slice(new_capacity);
+
+        let (head, tail) = self.live_slices();
+        new_data[..head.len()].copy_from_slice(head);
+        new_data[head.len()..self.amount].copy_from_slice(tail);
+
+        self.data = new_data;
+        self.read = 0;
+    }
+}
+
+impl<T: Copy> Queue for Elastic<T> {
+    type Item = T;
+
+    /// Return the number of items in the queue.
+    fn len(&self) -> usize {
+        self.amount
+    }
+
+    /// Return the maximum capacity of this queue. The number of items that can be enqueued
+    /// before the queue starts rejecting items is `q.capacity() - q.len()`, regardless of its
+    /// [current capacity](Elastic::current_capacity).
+    fn capacity(&self) -> usize {
+        self.max_capacity
+    }
+
+    /// Attempt to enqueue the next item, growing the queue if it is full.
+    ///
+    /// Will return the item if the queue is full and at its maximum capacity at the time of calling.
+    fn enqueue(&mut self, item: T) -> Option<T> {
+        if self.amount == self.current_capacity() && !self.grow() {
+            Some(item)
+        } else {
+            self.data[self.write_to()].write(item);
+            self.amount += 1;
+
+            None
+        }
+    }
+
+    /// Expose a non-empty slice of memory for the client code to fill with items that should
+    /// be enqueued, growing the queue if it is full.
+    ///
+    /// Will return `None` if the queue is full and at its maximum capacity at the time of calling.
+    fn expose_slots(&mut self) -> Option<&mut [MaybeUninit<T>]> {
+        if self.amount == self.current_capacity() && !self.grow() {
+            None
+        } else {
+            Some(self.writeable_slice())
+        }
+    }
+
+    /// Inform the queue that `amount` many items have been written to the first `amount`
+    /// indices of the `expose_slots` it has most recently exposed.
+    ///
+    /// #### Invariants
+    ///
+    /// Callers must have written into (at least) the `amount` many first `expose_slots` that
+    /// were most recently exposed. Failure to uphold this invariant may cause undefined behavior.
+    ///
+    /// #### Safety
+    ///
+    /// The queue will assume the first `amount` many `expose_slots` that were most recently
+    /// exposed to contain initialized memory after this call, even if the memory it exposed was
+    /// originally uninitialized. Violating the invariants will cause the queue to read undefined
+    /// memory, which triggers undefined behavior.
+    unsafe fn consider_enqueued(&mut self, amount: usize) {
+        debug_assert!(
+            self.amount + amount <= self.current_capacity(),
+            "cannot consider more items enqueued than the queue has slots"
+        );
+
+        self.amount += amount;
+    }
+
+    /// Attempt to dequeue the next item, shrinking the queue if it becomes sparsely filled.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn dequeue(&mut self) -> Option<T> {
+        if self.amount == 0 {
+            None
+        } else {
+            debug_assert!(
+                self.read < self.current_capacity(),
+                "read index out of bounds"
+            );
+
+            let previous_read = self.read;
+            // Advance the read index by 1 or reset to 0 if at capacity.
+            self.read = (self.read + 1) % self.current_capacity();
+            self.amount -= 1;
+
+            let item = unsafe { self.data[previous_read].assume_init() };
+            self.shrink_if_sparse();
+
+            Some(item)
+        }
+    }
+
+    /// Return the next item that would be dequeued, without removing it from the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn peek(&self) -> Option<T> {
+        if self.amount == 0 {
+            None
+        } else {
+            Some(unsafe { self.data[self.read].assume_init() })
+        }
+    }
+
+    /// Return the most recently enqueued item, without removing it from the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn last(&self) -> Option<T> {
+        if self.amount == 0 {
+            None
+        } else {
+            Some(unsafe {
+                self.data[(self.read + self.amount - 1) % self.current_capacity()].assume_init()
+            })
+        }
+    }
+
+    /// Expose a non-empty slice of items to be dequeued.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn expose_items(&mut self) -> Option<&[T]> {
+        if self.amount == 0 {
+            None
+        } else {
+            Some(unsafe { self.readable_slice().assume_init_ref() })
+        }
+    }
+
+    /// Mark `amount` many items as having been dequeued, shrinking the queue if it becomes
+    /// sparsely filled.
+    ///
+    /// #### Invariants
+    ///
+    /// Callers must not mark items as dequeued that had not previously been exposed by
+    /// `expose_items`.
+    fn consider_dequeued(&mut self, amount: usize) {
+        debug_assert!(
+            amount <= self.amount,
+            "cannot consider more items dequeued than the queue contains"
+        );
+
+        self.read = (self.read + amount) % self.current_capacity();
+        self.amount -= amount;
+        self.shrink_if_sparse();
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Elastic<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (head, tail) = self.live_slices();
+        let (head, tail) = unsafe { (head.assume_init_ref(), tail.assume_init_ref()) };
+
+        f.debug_struct("Elastic")
+            .field("min_capacity", &self.min_capacity)
+            .field("max_capacity", &self.max_capacity)
+            .field("current_capacity", &self.current_capacity())
+            .field("len", &self.amount)
+            .field("data", &DebugItems(head, tail))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn enqueues_and_dequeues_with_correct_amount() {
+        let mut queue: Elastic<u8> = Elastic::new(2, 8);
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.enqueue(21), None);
+        assert_eq!(queue.enqueue(196), None);
+        assert_eq!(queue.len(), 3);
+
+        assert_eq!(queue.enqueue(233), None);
+        assert_eq!(queue.len(), 4);
+
+        // Queue should be first-in, first-out.
+        assert_eq!(queue.dequeue(), Some(7));
+        assert_eq!(queue.len(), 3);
+    }
+
+    #[test]
+    fn bulk_enqueues_and_dequeues_with_correct_amount() {
+        let mut queue: Elastic<u8> = Elastic::new(4, 4);
+        let mut buf: [MaybeUninit<u8>; 4] = [MaybeUninit::uninit(); 4];
+
+        let enqueue_amount = queue.bulk_enqueue(b"ufo");
+        let dequeue_amount = queue.bulk_dequeue_uninit(&mut buf);
+
+        assert_eq!(enqueue_amount, dequeue_amount);
+    }
+
+    #[test]
+    fn returns_item_on_enqueue_when_queue_is_full_at_max_capacity() {
+        let mut queue: Elastic<u8> = Elastic::new(1, 2);
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.enqueue(8), None);
+
+        assert_eq!(queue.enqueue(0), Some(0))
+    }
+
+    #[test]
+    fn returns_none_on_dequeue_when_queue_is_empty() {
+        let mut queue: Elastic<u8> = Elastic::new(1, 1);
+
+        // Enqueue and then dequeue an item.
+        let _ = queue.enqueue(7);
+        let _ = queue.dequeue();
+
+        // The queue is now empty.
+        assert!(queue.dequeue().is_none());
+    }
+
+    #[test]
+    fn doubles_capacity_when_full() {
+        let mut queue: Elastic<u8> = Elastic::new(2, 5);
+        assert_eq!(queue.current_capacity(), 2);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2]), 2);
+        assert_eq!(queue.current_capacity(), 2);
+
+        assert_eq!(queue.enqueue(3), None);
+        assert_eq!(queue.current_capacity(), 4);
+
+        assert_eq!(queue.enqueue(4), None);
+        assert_eq!(queue.current_capacity(), 4);
+
+        // Growth is capped at the maximum capacity.
+        assert_eq!(queue.enqueue(5), None);
+        assert_eq!(queue.current_capacity(), 5);
+
+        for item in 1..=5 {
+            assert_eq!(queue.dequeue(), Some(item));
+        }
+    }
+
+    #[test]
+    fn halves_capacity_when_sparse() {
+        let mut queue: Elastic<u8> = Elastic::new(2, 16);
+        for item in 0..16 {
+            assert_eq!(queue.enqueue(item), None);
+        }
+        assert_eq!(queue.current_capacity(), 16);
+
+        // Dequeue until only four items (a quarter of the capacity) remain.
+        for item in 0..12 {
+            assert_eq!(queue.dequeue(), Some(item));
+        }
+        assert_eq!(queue.current_capacity(), 16);
+
+        // Dropping below a quarter of the capacity halves the capacity.
+        assert_eq!(queue.dequeue(), Some(12));
+        assert_eq!(queue.current_capacity(), 8);
+
+        assert_eq!(queue.dequeue(), Some(13));
+        assert_eq!(queue.current_capacity(), 8);
+        assert_eq!(queue.dequeue(), Some(14));
+        assert_eq!(queue.current_capacity(), 4);
+
+        // The capacity never drops below the minimum capacity.
+        assert_eq!(queue.dequeue(), Some(15));
+        assert_eq!(queue.current_capacity(), 2);
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.current_capacity(), 2);
+    }
+
+    #[test]
+    fn preserves_order_when_growing_a_wrapped_queue() {
+        let mut queue: Elastic<u8> = Elastic::new(4, 8);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(5), None);
+        assert_eq!(queue.enqueue(6), None);
+        assert_eq!(queue.current_capacity(), 4);
+
+        // Growing re-linearizes the items, so all free slots are exposed at once.
+        assert_eq!(queue.bulk_enqueue(&[7, 8, 9, 10]), 4);
+        assert_eq!(queue.current_capacity(), 8);
+
+        let mut buf = [0; 8];
+        assert_eq!(queue.bulk_dequeue(&mut buf), 8);
+        assert_eq!(buf, [3, 4, 5, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn shrinks_on_consider_dequeued() {
+        let mut queue: Elastic<u8> = Elastic::new(2, 8);
+        for item in 0..8 {
+            assert_eq!(queue.enqueue(item), None);
+        }
+        assert_eq!(queue.current_capacity(), 8);
+
+        let mut buf = [0; 7];
+        assert_eq!(queue.bulk_dequeue(&mut buf), 7);
+        assert_eq!(buf, [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(queue.current_capacity(), 4);
+        assert_eq!(queue.dequeue(), Some(7));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_zero_min_capacity() {
+        let _queue: Elastic<u8> = Elastic::new(0, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_min_capacity_exceeding_max_capacity() {
+        let _queue: Elastic<u8> = Elastic::new(5, 4);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "cannot consider more items dequeued than the queue contains")]
+    fn panics_on_considering_too_many_items_dequeued() {
+        let mut queue: Elastic<u8> = Elastic::new(4, 4);
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        queue.consider_dequeued(4);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "read index out of bounds")]
+    fn panics_on_dequeue_with_corrupted_read_index() {
+        let mut queue: Elastic<u8> = Elastic::new(4, 4);
+
+        assert_eq!(queue.enqueue(7), None);
+        queue.read = 4;
+        let _ = queue.dequeue();
+    }
+
+    #[test]
+    fn reports_max_capacity_and_remaining() {
+        let mut queue: Elastic<u8> = Elastic::new(2, 4);
+        assert_eq!(queue.capacity(), 4);
+        assert_eq!(queue.remaining(), 4);
+
+        // Growing does not change the reported capacity.
+        assert_eq!(queue.enqueue(1), None);
+        assert_eq!(queue.enqueue(2), None);
+        assert_eq!(queue.enqueue(3), None);
+        assert_eq!(queue.current_capacity(), 4);
+        assert_eq!(queue.capacity(), 4);
+        assert_eq!(queue.remaining(), 1);
+
+        assert_eq!(queue.enqueue(4), None);
+        assert_eq!(queue.remaining(), 0);
+    }
+
+    #[test]
+    fn last_tracks_newest_item() {
+        let mut queue: Elastic<u8> = Elastic::new(3, 3);
+        assert_eq!(queue.last(), None);
+
+        assert_eq!(queue.enqueue(1), None);
+        assert_eq!(queue.last(), Some(1));
+        assert_eq!(queue.enqueue(2), None);
+        assert_eq!(queue.enqueue(3), None);
+        assert_eq!(queue.last(), Some(3));
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.last(), Some(3));
+
+        // This item is stored at the start of the ring-buffer.
+        assert_eq!(queue.enqueue(4), None);
+        assert_eq!(queue.last(), Some(4));
+
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.last(), Some(4));
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.last(), None);
+    }
+
+    #[test]
+    fn never_shrinks_under_never_policy() {
+        let mut queue: Elastic<u8> = Elastic::new(2, 16).with_shrink_policy(ShrinkPolicy::Never);
+        assert_eq!(queue.bulk_enqueue_remainder(&[0; 16]), &[]);
+        assert_eq!(queue.current_capacity(), 16);
+
+        assert_eq!(queue.bulk_dequeue(&mut [0; 16]), 16);
+        assert_eq!(queue.current_capacity(), 16);
+    }
+
+    #[test]
+    fn shrinks_as_soon_as_possible_under_eager_policy() {
+        let mut queue: Elastic<u8> = Elastic::new(2, 8).with_shrink_policy(ShrinkPolicy::Eager);
+        assert_eq!(queue.shrink_policy(), ShrinkPolicy::Eager);
+        for item in 0..8 {
+            assert_eq!(queue.enqueue(item), None);
+        }
+        assert_eq!(queue.current_capacity(), 8);
+
+        for item in 0..3 {
+            assert_eq!(queue.dequeue(), Some(item));
+        }
+        assert_eq!(queue.current_capacity(), 8);
+
+        // The remaining four items fit into half of the capacity.
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.current_capacity(), 4);
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.current_capacity(), 4);
+        assert_eq!(queue.dequeue(), Some(5));
+        assert_eq!(queue.current_capacity(), 2);
+    }
+
+    #[test]
+    fn shrinks_below_ratio_under_when_below_policy() {
+        let mut queue: Elastic<u8> =
+            Elastic::new(2, 16).with_shrink_policy(ShrinkPolicy::WhenBelow(0.125));
+        for item in 0..16 {
+            assert_eq!(queue.enqueue(item), None);
+        }
+
+        // Dequeue until only two items (an eighth of the capacity) remain.
+        for item in 0..14 {
+            assert_eq!(queue.dequeue(), Some(item));
+        }
+        assert_eq!(queue.current_capacity(), 16);
+
+        assert_eq!(queue.dequeue(), Some(14));
+        assert_eq!(queue.current_capacity(), 8);
+    }
+
+    #[test]
+    fn waits_for_items_to_fit_when_ratio_exceeds_one_half() {
+        let mut queue: Elastic<u8> =
+            Elastic::new(2, 16).with_shrink_policy(ShrinkPolicy::WhenBelow(0.9));
+        for item in 0..16 {
+            assert_eq!(queue.enqueue(item), None);
+        }
+
+        // Fewer than 90% of the slots hold items, but the items do not fit into half of them.
+        for item in 0..7 {
+            assert_eq!(queue.dequeue(), Some(item));
+            assert_eq!(queue.current_capacity(), 16);
+        }
+
+        assert_eq!(queue.dequeue(), Some(7));
+        assert_eq!(queue.current_capacity(), 8);
+        for item in 8..16 {
+            assert_eq!(queue.dequeue(), Some(item));
+        }
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "the shrink ratio of an Elastic queue must lie in (0.0, 1.0]")]
+    fn panics_on_zero_shrink_ratio() {
+        let _: Elastic<u8> = Elastic::new(1, 4).with_shrink_policy(ShrinkPolicy::WhenBelow(0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "the shrink ratio of an Elastic queue must lie in (0.0, 1.0]")]
+    fn panics_on_nan_shrink_ratio() {
+        let _: Elastic<u8> =
+            Elastic::new(1, 4).with_shrink_policy(ShrinkPolicy::WhenBelow(f32::NAN));
+    }
+
+    #[test]
+    fn grows_by_configured_factor() {
+        let mut queue: Elastic<u8> = Elastic::new(4, 32).with_growth_factor(1.5);
+        assert_eq!(queue.growth_factor(), 1.5);
+
+        let mut capacities = Vec::new();
+        for item in 0..32 {
+            assert_eq!(queue.enqueue(item), None);
+            if capacities.last() != Some(&queue.current_capacity()) {
+                capacities.push(queue.current_capacity());
+            }
+        }
+
+        // Each capacity is 1.5 times the previous one, rounded down, capped at the maximum.
+        assert_eq!(capacities, [4, 6, 9, 13, 19, 28, 32]);
+    }
+
+    #[test]
+    fn grows_by_at_least_one_slot() {
+        let mut queue: Elastic<u8> = Elastic::new(1, 4).with_growth_factor(1.1);
+
+        assert_eq!(queue.enqueue(1), None);
+        assert_eq!(queue.enqueue(2), None);
+        assert_eq!(queue.current_capacity(), 2);
+        assert_eq!(queue.enqueue(3), None);
+        assert_eq!(queue.current_capacity(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "the growth factor of an Elastic queue must be greater than 1.0")]
+    fn panics_on_growth_factor_of_one() {
+        let _: Elastic<u8> = Elastic::new(1, 4).with_growth_factor(1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the growth factor of an Elastic queue must be greater than 1.0")]
+    fn panics_on_nan_growth_factor() {
+        let _: Elastic<u8> = Elastic::new(1, 4).with_growth_factor(f32::NAN);
+    }
+
+    #[test]
+    fn test_debug_impl() {
+        let mut queue: Elastic<u8> = Elastic::new(2, 4);
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Elastic { min_capacity: 2, max_capacity: 4, current_capacity: 2, len: 1, data: [7] }"
+        );
+
+        assert_eq!(queue.enqueue(21), None);
+        assert_eq!(queue.enqueue(196), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Elastic { min_capacity: 2, max_capacity: 4, current_capacity: 4, len: 3, data: [7, 21, 196] }"
+        );
+    }
+}
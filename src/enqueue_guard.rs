@@ -0,0 +1,114 @@
+use core::fmt;
+
+use crate::Queue;
+
+/// A reservation of free slots in a queue, obtained via [`Queue::reserve`]. Pushing an item
+/// through the guard cannot fail as long as the reservation has not been used up, so multi-step
+/// writes need not handle a full queue midway.
+///
+/// The guard borrows the queue mutably, so nothing else can take up the reserved slots in the
+/// meantime. Dropping the guard releases any slots that were not pushed to.
+pub struct EnqueueGuard<'a, Q: ?Sized> {
+    queue: &'a mut Q,
+    reserved: usize,
+}
+
+impl<'a, Q: Queue> EnqueueGuard<'a, Q> {
+    /// Reserve `n` slots of a queue, or return `None` if fewer than `n` slots are free.
+    pub(crate) fn new(queue: &'a mut Q, n: usize) -> Option<Self> {
+        if queue.remaining() < n {
+            None
+        } else {
+            Some(EnqueueGuard { queue, reserved: n })
+        }
+    }
+
+    /// Enqueue an item into one of the reserved slots.
+    ///
+    /// Panic if the reserved slots have all been used up.
+    pub fn push(&mut self, item: Q::Item) {
+        assert!(
+            self.reserved > 0,
+            "cannot push more items than slots were reserved"
+        );
+
+        let rejected = self.queue.enqueue(item);
+        debug_assert!(
+            rejected.is_none(),
+            "queue rejected item despite reservation"
+        );
+        self.reserved -= 1;
+    }
+
+    /// Return how many reserved slots have not been pushed to yet.
+    pub fn reserved(&self) -> usize {
+        self.reserved
+    }
+}
+
+impl<Q: ?Sized + fmt::Debug> fmt::Debug for EnqueueGuard<'_, Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnqueueGuard")
+            .field("reserved", &self.reserved)
+            .field("queue", &self.queue)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::format;
+
+    use super::*;
+    use crate::Static;
+
+    #[test]
+    fn pushes_reserved_items() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.enqueue(1), None);
+
+        let mut guard = queue.reserve(3).unwrap();
+        assert_eq!(guard.reserved(), 3);
+        guard.push(2);
+        guard.push(3);
+        guard.push(4);
+        assert_eq!(guard.reserved(), 0);
+
+        assert!(queue.iter().eq([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn refuses_to_reserve_more_than_the_free_slots() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.bulk_enqueue(&[1, 2]), 2);
+
+        assert!(queue.reserve(3).is_none());
+        assert!(queue.reserve(2).is_some());
+        assert!(queue.reserve(0).is_some());
+        assert_eq!(queue.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot push more items than slots were reserved")]
+    fn panics_on_pushing_beyond_the_reservation() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        let mut guard = queue.reserve(1).unwrap();
+        guard.push(1);
+        guard.push(2);
+    }
+
+    #[test]
+    fn test_debug_impl() {
+        let mut queue: Static<u8, 2> = Static::new();
+
+        let mut guard = queue.reserve(2).unwrap();
+        guard.push(7);
+        assert_eq!(
+            format!("{:?}", guard),
+            "EnqueueGuard { reserved: 1, queue: Static { len: 1, data: [7] } }"
+        );
+    }
+}
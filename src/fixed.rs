@@ -1,64 +1,112 @@
 extern crate alloc;
 
-use alloc::alloc::{Allocator, Global};
+use crate::allocator::{self, Allocator, Global, Slots};
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 
+use core::cmp::min;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::mem::MaybeUninit;
+use core::ops::Index;
+use core::ptr;
+use core::sync::atomic::{compiler_fence, Ordering};
 
-use crate::Queue;
+use crate::{DebugItems, Deque, Queue, Static};
 
 /// A queue holding up to a certain number of items. The capacity is set upon
 /// creation and remains fixed. Performs a single heap allocation on creation.
 ///
 /// Use the methods of the [Queue] trait implementation to interact with the contents of the queue.
+///
+/// A queue of capacity zero is valid: it rejects every item it is asked to enqueue, and never
+/// has any items to dequeue.
+///
+/// The queue is [`Send`] if `T` and `A` are, and [`Sync`] if `T` and `A` are. These are derived
+/// automatically from the boxed slice it owns, since [`MaybeUninit`] does not affect them.
 pub struct Fixed<T, A: Allocator = Global> {
     /// Slice of memory, used as a ring-buffer.
-    data: Box<[MaybeUninit<T>], A>,
+    data: Slots<T, A>,
     /// Read index.
     read: usize,
     /// Amount of valid data.
     amount: usize,
+    /// Whether to overwrite slots with zeroes when their items are removed from the queue.
+    zeroizing: bool,
 }
 
 impl<T> Fixed<T> {
     /// Create a fixed-capacity queue. Panic if the initial memory allocation fails.
     pub fn new(capacity: usize) -> Self {
         Fixed {
-            data: Box::new_uninit_slice(capacity),
+            data: allocator::new_uninit_in(capacity, Global),
+            read: 0,
+            amount: 0,
+            zeroizing: false,
+        }
+    }
+
+    /// Create a fixed-capacity queue that overwrites the memory of every item with zeroes as soon
+    /// as the item is removed from the queue, so that sensitive data such as key material does
+    /// not linger in the ring-buffer. This applies to all ways of removing items: dequeueing,
+    /// [popping from the back](Deque::pop_back), draining, [clearing](Fixed::clear),
+    /// [retaining](Fixed::retain), [truncating](Fixed::truncate), and moving the items into a new
+    /// allocation via [`grow`](Fixed::grow) or [`shrink_to_fit`](Fixed::shrink_to_fit). Dropping
+    /// the queue zeroes all of its memory, too. The zeroes are written with volatile stores, so
+    /// the compiler cannot optimise them away. Panic if the initial memory allocation fails.
+    ///
+    /// Zeroing trades a small amount of performance for this guarantee. Note that copies of the
+    /// items handed out by the queue are not affected.
+    pub fn new_zeroizing(capacity: usize) -> Self {
+        Fixed {
+            data: allocator::new_zeroed_in(capacity, Global),
             read: 0,
             amount: 0,
+            zeroizing: true,
         }
     }
 
     /// Try to create a fixed-capacity queue. If the initial memory allocation fails, return `None` instead.
     pub fn try_new(capacity: usize) -> Option<Self> {
         Some(Fixed {
-            data: Box::try_new_uninit_slice(capacity).ok()?,
+            data: allocator::try_new_uninit_in(capacity, Global)?,
             read: 0,
             amount: 0,
+            zeroizing: false,
         })
     }
+
+    /// Create a fixed-capacity queue whose capacity is `capacity` clamped to lie between `min`
+    /// and `max` (both inclusive). Useful when the capacity is computed at runtime and should
+    /// neither be degenerate nor exhaust memory. Panic if `min > max`, or if the initial memory
+    /// allocation fails.
+    ///
+    /// Passing a `min` of zero allows a queue of capacity zero, which is valid.
+    pub fn with_capacity_clamped(capacity: usize, min: usize, max: usize) -> Self {
+        Self::new(capacity.clamp(min, max))
+    }
 }
 
 impl<T, A: Allocator> Fixed<T, A> {
     /// Create a fixed-capacity queue with a given memory allocator. Panic if the initial memory allocation fails.
     pub fn new_in(capacity: usize, alloc: A) -> Self {
         Fixed {
-            data: Box::new_uninit_slice_in(capacity, alloc),
+            data: allocator::new_uninit_in(capacity, alloc),
             read: 0,
             amount: 0,
+            zeroizing: false,
         }
     }
 
-    // /// Try to create a fixed-capacity queue with a given memory allocator. If the initial memory allocation fails, return `None` instead.
-    // pub fn try_new_in(capacity: usize, alloc: A) -> Option<Self> {
-    //     Some(Fixed {
-    //         data: Box::try_new_uninit_slice_in(capacity, alloc)?,
-    //         read: 0,
-    //         amount: 0,
-    //     })
-    // }
+    /// Try to create a fixed-capacity queue with a given memory allocator. If the initial memory allocation fails, return `None` instead.
+    pub fn try_new_in(capacity: usize, alloc: A) -> Option<Self> {
+        Some(Fixed {
+            data: allocator::try_new_uninit_in(capacity, alloc)?,
+            read: 0,
+            amount: 0,
+            zeroizing: false,
+        })
+    }
 
     fn is_data_contiguous(&self) -> bool {
         self.read + self.amount < self.capacity()
@@ -86,13 +134,539 @@ impl<T, A: Allocator> Fixed<T, A> {
 
     /// Return the capacity with which thise queue was initialised.
     ///
-    /// The number of free item slots at any time is `q.capacity() - q.amount()`.
+    /// The number of free item slots at any time is `q.capacity() - q.len()`.
     pub fn capacity(&self) -> usize {
         self.data.len()
     }
 
+    /// Return the items of the queue as two slices, in FIFO order: the front of the queue
+    /// comes first, and the second slice is empty if the items are stored contiguously.
+    /// Mirrors [`VecDeque::as_slices`](https://doc.rust-lang.org/std/collections/struct.VecDeque.html#method.as_slices).
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (head, tail) = self.live_slices();
+
+        unsafe { (head.assume_init_ref(), tail.assume_init_ref()) }
+    }
+
+    /// Return whether the items of the queue are stored contiguously, that is, whether they do
+    /// not wrap around the end of the ring-buffer. If so, [`expose_items`](Queue::expose_items)
+    /// exposes all items at once, and the second slice of [`as_slices`](Self::as_slices) is
+    /// empty. Otherwise, either dequeue in several steps or call
+    /// [`make_contiguous`](Self::make_contiguous) first.
+    pub fn is_contiguous(&self) -> bool {
+        self.read + self.amount <= self.capacity()
+    }
+
+    /// Return up to `n` items from the front of the queue as two slices, in FIFO order, without
+    /// dequeueing them. Works like [`as_slices`](Self::as_slices), but limited to at most `n`
+    /// items; the second slice is empty if those items are stored contiguously.
+    pub fn peek_slice(&self, n: usize) -> (&[T], &[T]) {
+        let (head, tail) = self.as_slices();
+        let n = min(n, self.amount);
+
+        if n <= head.len() {
+            (&head[..n], &[])
+        } else {
+            (head, &tail[..n - head.len()])
+        }
+    }
+
+    /// Return the number of items at the front of the queue that satisfy a predicate, up to the
+    /// first item that does not, without dequeueing any of them. Useful for checking whether a
+    /// complete token has arrived before consuming it via
+    /// [`dequeue_while`](Queue::dequeue_while).
+    pub fn peek_while<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        let (head, tail) = self.as_slices();
+        head.iter()
+            .chain(tail)
+            .take_while(|item| pred(item))
+            .count()
+    }
+
+    /// Copy up to `out.len()` items from the front of the queue into `out`, in FIFO order, without
+    /// dequeueing them, and return how many items were copied.
+    pub fn copy_to_slice(&self, out: &mut [T]) -> usize
+    where
+        T: Copy,
+    {
+        let (head, tail) = self.peek_slice(out.len());
+        out[..head.len()].copy_from_slice(head);
+        out[head.len()..head.len() + tail.len()].copy_from_slice(tail);
+
+        head.len() + tail.len()
+    }
+
+    /// Return a mutable reference to the next item that would be dequeued, without removing it
+    /// from the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        if self.amount == 0 {
+            None
+        } else {
+            Some(unsafe { self.data[self.read].assume_init_mut() })
+        }
+    }
+
+    /// Return the items of the queue as two mutable slices, in FIFO order: the front of the queue
+    /// comes first, and the second slice is empty if the items are stored contiguously. This
+    /// allows modifying items in place, without dequeueing and re-enqueueing them.
+    /// Mirrors [`VecDeque::as_mut_slices`](https://doc.rust-lang.org/std/collections/struct.VecDeque.html#method.as_mut_slices).
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let head_len = min(self.amount, self.capacity() - self.read);
+        let tail_len = self.amount - head_len;
+
+        // The items wrapping around the end of the ring-buffer never reach the read index.
+        let (before_read, from_read) = self.data.split_at_mut(self.read);
+
+        unsafe {
+            (
+                from_read[..head_len].assume_init_mut(),
+                before_read[..tail_len].assume_init_mut(),
+            )
+        }
+    }
+
+    /// Rearrange the items of the queue in place, such that they are stored contiguously
+    /// starting at the beginning of the ring-buffer, and return them as a single slice in FIFO
+    /// order. Mirrors [`VecDeque::make_contiguous`](https://doc.rust-lang.org/std/collections/struct.VecDeque.html#method.make_contiguous).
+    ///
+    /// Afterwards, [`Queue::expose_items`] exposes all items of the queue at once.
+    pub fn make_contiguous(&mut self) -> &[T] {
+        // Rotating moves the uninitialized slots along with the initialized ones, without ever
+        // reading them as `T`.
+        self.data.rotate_left(self.read);
+        self.read = 0;
+
+        unsafe { self.data[..self.amount].assume_init_ref() }
+    }
+
+    /// Return whether the queue contains an item equal to the given one.
+    pub fn contains(&self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let (head, tail) = self.as_slices();
+
+        head.contains(item) || tail.contains(item)
+    }
+
+    /// Return the position of the first item for which the predicate returns `true`, counted
+    /// from the front of the queue (so position `0` is the next item that would be dequeued).
+    ///
+    /// Will return `None` if the predicate returns `false` for all items.
+    pub fn position<F: FnMut(&T) -> bool>(&self, pred: F) -> Option<usize> {
+        let (head, tail) = self.as_slices();
+
+        head.iter().chain(tail).position(pred)
+    }
+
+    /// Return the index in the ring-buffer of the next item that would be dequeued.
+    ///
+    /// This is meant for diagnostics only: the layout of the ring-buffer is an implementation
+    /// detail that may change in any release.
+    pub fn read_index(&self) -> usize {
+        self.read
+    }
+
+    /// Return the index in the ring-buffer of the slot that the next enqueued item would be
+    /// written to.
+    ///
+    /// This is meant for diagnostics only: the layout of the ring-buffer is an implementation
+    /// detail that may change in any release.
+    pub fn write_index(&self) -> usize {
+        self.write_to()
+    }
+
+    /// Return the total number of free slots, exactly like [`Queue::remaining`].
+    pub fn free(&self) -> usize {
+        self.capacity() - self.amount
+    }
+
+    /// Return the number of free slots that directly follow the last item without wrapping around
+    /// the end of the ring-buffer, which is the length of the slice that [`Queue::expose_slots`]
+    /// would currently expose.
+    pub fn contiguous_free(&self) -> usize {
+        if self.amount == self.capacity() {
+            0
+        } else if self.is_data_contiguous() {
+            self.capacity() - self.write_to()
+        } else {
+            self.read - self.write_to()
+        }
+    }
+
+    /// Expose all free slots of the queue at once, as two slices of memory: first the slots
+    /// directly following the last item, then the slots that wrap around to the beginning of the
+    /// ring-buffer. The second slice is empty if the free slots are contiguous, and both are empty
+    /// if the queue is full.
+    ///
+    /// To enqueue items, fill the first slice and then the second one, and afterwards inform the
+    /// queue of the total number of items written via [`Queue::consider_enqueued`]. Its invariants
+    /// apply to the slots of both slices in that order.
+    pub fn expose_all_slots(&mut self) -> (&mut [MaybeUninit<T>], &mut [MaybeUninit<T>]) {
+        if self.amount == self.capacity() {
+            (&mut [], &mut [])
+        } else {
+            let read = self.read;
+            let write_to = self.write_to();
+
+            if self.is_data_contiguous() {
+                let (wrapped, from_write) = self.data.split_at_mut(write_to);
+                (from_write, &mut wrapped[..read])
+            } else {
+                (&mut self.data[write_to..read], &mut [])
+            }
+        }
+    }
+
+    /// Return the two slices of memory that hold the items of the queue, in FIFO order. The
+    /// second slice is empty unless the items wrap around the end of the ring-buffer.
+    fn live_slices(&self) -> (&[MaybeUninit<T>], &[MaybeUninit<T>]) {
+        let head_len = core::cmp::min(self.amount, self.capacity() - self.read);
+        let tail_len = self.amount - head_len;
+
+        (
+            &self.data[self.read..self.read + head_len],
+            &self.data[..tail_len],
+        )
+    }
+
+    /// Overwrite `count` many slots with zeroes, starting at index `start` and wrapping around the
+    /// end of the ring-buffer, if this queue was created by [`Fixed::new_zeroizing`].
+    fn zero_slots(&mut self, start: usize, count: usize) {
+        if self.zeroizing {
+            let capacity = self.capacity();
+            for i in 0..count {
+                // A plain store right before the memory is freed could be optimised away.
+                unsafe {
+                    ptr::write_volatile(
+                        &mut self.data[(start + i) % capacity],
+                        MaybeUninit::zeroed(),
+                    );
+                }
+            }
+            compiler_fence(Ordering::SeqCst);
+        }
+    }
+
     fn write_to(&self) -> usize {
-        (self.read + self.amount) % self.capacity()
+        if self.capacity() == 0 {
+            // There are no slots to write to, and no capacity to take the remainder by.
+            0
+        } else {
+            (self.read + self.amount) % self.capacity()
+        }
+    }
+
+    /// Check the internal consistency of the indices of the queue, and panic if they are
+    /// inconsistent. Intended for tests and fuzzing, and only available in tests or with the
+    /// `validate` feature.
+    #[cfg(any(test, feature = "validate"))]
+    pub fn debug_validate(&self) {
+        let capacity = self.capacity();
+
+        assert!(
+            self.amount <= capacity,
+            "queue contains more items than its capacity"
+        );
+
+        if capacity == 0 {
+            assert_eq!(
+                self.read, 0,
+                "read index of a queue without capacity is not zero"
+            );
+        } else {
+            assert!(self.read < capacity, "read index out of bounds");
+            assert!(self.write_to() < capacity, "write index out of bounds");
+            assert_eq!(
+                (self.write_to() + capacity - self.amount) % capacity,
+                self.read,
+                "write index inconsistent with read index and length"
+            );
+        }
+    }
+}
+
+impl<T: Copy, A: Allocator> Fixed<T, A> {
+    /// Return an iterator over the items of the queue, from front to back, without dequeueing them.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        let (head, tail) = self.as_slices();
+
+        head.iter().chain(tail).copied()
+    }
+
+    /// Return the item at the given position, counted from the front of the queue (so index
+    /// `0` holds the next item that would be dequeued), without dequeueing it.
+    ///
+    /// Will return `None` if `index >= self.len()`.
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index < self.amount {
+            Some(unsafe { self.data[(self.read + index) % self.capacity()].assume_init() })
+        } else {
+            None
+        }
+    }
+
+    /// Enqueue an item, even if the queue is full. If it is, the oldest item is overwritten
+    /// and returned. Otherwise, this behaves exactly like [`Queue::enqueue`] and returns `None`.
+    ///
+    /// In a queue of capacity zero, the item itself is returned.
+    pub fn enqueue_overwrite(&mut self, item: T) -> Option<T> {
+        if self.capacity() == 0 {
+            Some(item)
+        } else if self.amount == self.capacity() {
+            // The write index coincides with the read index when the queue is full.
+            let evicted = unsafe { self.data[self.read].assume_init() };
+            self.data[self.read].write(item);
+            self.read = (self.read + 1) % self.capacity();
+
+            Some(evicted)
+        } else {
+            self.enqueue(item)
+        }
+    }
+
+    /// Return an iterator that dequeues the items of the queue, from front to back. When the
+    /// iterator is dropped, all items it has not yielded are removed from the queue as well, so
+    /// that the queue is empty afterwards (but keeps its capacity).
+    pub fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        Drain { queue: self }
+    }
+
+    /// Enqueue items from an iterator until the iterator is exhausted or the queue is full, and
+    /// return how many items were enqueued. Items are only taken from the iterator if they can
+    /// be enqueued.
+    pub fn extend_counted<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        let mut count = 0;
+
+        for item in iter.into_iter().take(self.remaining()) {
+            let write = self.write_to();
+            self.data[write].write(item);
+            self.amount += 1;
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Remove all items from the queue. The queue keeps its capacity and its allocation.
+    pub fn clear(&mut self) {
+        self.zero_slots(self.read, self.amount);
+        self.read = 0;
+        self.amount = 0;
+    }
+
+    /// Remove all items from the queue, exactly like [`Fixed::clear`].
+    pub fn reset(&mut self) {
+        self.clear();
+    }
+
+    /// Retain only the items for which the predicate returns `true`, removing all others from
+    /// the queue. The retained items keep their order. Mirrors [`Vec::retain`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.retain).
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut kept = 0;
+
+        // Compact the retained items towards the front of the queue, which never overwrites an
+        // item that has not been visited yet.
+        for i in 0..self.amount {
+            let item = unsafe { self.data[(self.read + i) % self.capacity()].assume_init() };
+            if f(&item) {
+                let write = (self.read + kept) % self.capacity();
+                self.data[write].write(item);
+                kept += 1;
+            }
+        }
+
+        self.zero_slots(self.read + kept, self.amount - kept);
+        self.amount = kept;
+    }
+
+    /// Shorten the queue to the given length by removing the most recently enqueued items, so
+    /// that the first `len` items remain. Mirrors [`Vec::truncate`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.truncate).
+    ///
+    /// Has no effect if `len >= self.len()`.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.amount {
+            self.zero_slots(self.read + len, self.amount - len);
+            self.amount = len;
+        }
+    }
+
+    /// Rotate the queue `n` places to the left, so that the first `n` items move to the back of
+    /// the queue, in order. Mirrors [`VecDeque::rotate_left`](https://doc.rust-lang.org/std/collections/struct.VecDeque.html#method.rotate_left).
+    ///
+    /// Takes `O(min(n, self.len() - n))` time, or constant time if the queue is full.
+    ///
+    /// Panic if `n > self.len()`.
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(
+            n <= self.amount,
+            "cannot rotate a queue by more places than it has items"
+        );
+
+        if self.amount == self.capacity() {
+            // The slots of a full queue all hold items, so only the read index needs to move.
+            if n > 0 {
+                self.read = (self.read + n) % self.capacity();
+            }
+        } else if n <= self.amount - n {
+            for _ in 0..n {
+                self.move_front_to_back();
+            }
+        } else {
+            for _ in 0..self.amount - n {
+                self.move_back_to_front();
+            }
+        }
+    }
+
+    /// Rotate the queue `n` places to the right, so that the last `n` items move to the front of
+    /// the queue, in order. Mirrors [`VecDeque::rotate_right`](https://doc.rust-lang.org/std/collections/struct.VecDeque.html#method.rotate_right).
+    ///
+    /// Takes `O(min(n, self.len() - n))` time, or constant time if the queue is full.
+    ///
+    /// Panic if `n > self.len()`.
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(
+            n <= self.amount,
+            "cannot rotate a queue by more places than it has items"
+        );
+
+        self.rotate_left(self.amount - n);
+    }
+
+    /// Move the first item of a queue that is neither empty nor full to its back.
+    fn move_front_to_back(&mut self) {
+        let front = self.read;
+        let item = unsafe { self.data[front].assume_init() };
+        let write = self.write_to();
+        self.data[write].write(item);
+        self.read = (self.read + 1) % self.capacity();
+        if self.amount < self.capacity() {
+            self.zero_slots(front, 1);
+        }
+    }
+
+    /// Move the last item of a queue that is neither empty nor full to its front.
+    fn move_back_to_front(&mut self) {
+        let back = (self.read + self.amount - 1) % self.capacity();
+        let item = unsafe { self.data[back].assume_init() };
+        self.read = (self.read + self.capacity() - 1) % self.capacity();
+        self.data[self.read].write(item);
+        if self.amount < self.capacity() {
+            self.zero_slots(back, 1);
+        }
+    }
+
+    /// Move as many items as fit from the front of another queue to the back of this one, in
+    /// order, and return how many items were moved. Items that do not fit remain in the other
+    /// queue. Copies the items directly between the two ring-buffers.
+    pub fn append(&mut self, other: &mut Self) -> usize {
+        let (head, tail) = other.as_slices();
+
+        let head_amount = self.bulk_enqueue(head);
+        let tail_amount = if head_amount == head.len() {
+            self.bulk_enqueue(tail)
+        } else {
+            0
+        };
+
+        other.consider_dequeued(head_amount + tail_amount);
+        head_amount + tail_amount
+    }
+
+    /// Remove up to `n` items from the front of the queue without reading them, and return how
+    /// many items were removed. Removes all items if `n >= self.len()`.
+    pub fn discard(&mut self, n: usize) -> usize {
+        let amount = min(n, self.amount);
+        self.consider_dequeued(amount);
+
+        amount
+    }
+}
+
+impl<T: Copy, A: Allocator + Clone> Fixed<T, A> {
+    /// Increase the capacity of the queue to `new_capacity`, preserving its items. Performs a
+    /// single heap allocation with the allocator of this queue, and moves the items into it.
+    ///
+    /// Panic if `new_capacity` is less than the current capacity, or if the memory allocation fails.
+    pub fn grow(&mut self, new_capacity: usize) {
+        assert!(
+            new_capacity >= self.capacity(),
+            "cannot grow a Fixed queue to a smaller capacity"
+        );
+
+        let data = self.linearized_data(new_capacity);
+        self.zero_slots(0, self.capacity());
+        self.data = data;
+        self.read = 0;
+    }
+
+    /// Remove all items from the queue, and then enqueue all given items. Reuses the existing
+    /// allocation if `items.len() <= self.capacity()`; otherwise, this grows the capacity of the
+    /// queue to `items.len()`, exactly like [`Fixed::grow`].
+    ///
+    /// Panic if a necessary memory allocation fails.
+    pub fn reset_and_fill(&mut self, items: &[T]) {
+        self.clear();
+        if items.len() > self.capacity() {
+            self.grow(items.len());
+        }
+
+        self.data[..items.len()].write_copy_of_slice(items);
+        self.amount = items.len();
+    }
+
+    /// Enqueue all given items, growing the capacity of the queue first if they do not fit into
+    /// its free slots. Growing works like [`Fixed::grow`], and at least doubles the capacity, so
+    /// that repeated overflows do not each cause an allocation. If the items fit, no allocation
+    /// happens.
+    ///
+    /// Panic if a necessary memory allocation fails.
+    pub fn bulk_enqueue_growing(&mut self, buffer: &[T]) {
+        if buffer.len() > self.remaining() {
+            let needed = self.amount + buffer.len();
+            self.grow(core::cmp::max(needed, 2 * self.capacity()));
+        }
+
+        let amount = self.bulk_enqueue(buffer);
+        debug_assert_eq!(amount, buffer.len());
+    }
+
+    /// Reduce the capacity of the queue to its current length, preserving its items. Performs a
+    /// single heap allocation with the allocator of this queue and moves the items into it,
+    /// unless the capacity already equals the length, in which case this is a no-op.
+    ///
+    /// Any slots previously exposed by [`Queue::expose_slots`] are invalidated, so
+    /// [`Queue::consider_enqueued`] must not be called for them afterwards.
+    ///
+    /// Panic if the memory allocation fails.
+    pub fn shrink_to_fit(&mut self) {
+        if self.capacity() != self.amount {
+            let data = self.linearized_data(self.amount);
+            self.zero_slots(0, self.capacity());
+            self.data = data;
+            self.read = 0;
+        }
+    }
+
+    /// Allocate a slice of memory of the given capacity, and copy the items of the queue into
+    /// it, such that the next item to be read is located at index zero. The capacity must be at
+    /// least `self.len()`.
+    fn linearized_data(&self, capacity: usize) -> Slots<T, A> {
+        let alloc = allocator::allocator(&self.data).clone();
+        let mut data = if self.zeroizing {
+            allocator::new_zeroed_in(capacity, alloc)
+        } else {
+            allocator::new_uninit_in(capacity, alloc)
+        };
+
+        let (head, tail) = self.live_slices();
+        data[..head.len()].copy_from_slice(head);
+        data[head.len()..self.amount].copy_from_slice(tail);
+
+        data
     }
 }
 
@@ -104,6 +678,11 @@ impl<T: Copy, A: Allocator> Queue for Fixed<T, A> {
         self.amount
     }
 
+    /// Return the capacity with which this queue was initialised.
+    fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
     /// Attempt to enqueue the next item.
     ///
     /// Will return the item if the queue is full at the time of calling.
@@ -111,13 +690,20 @@ impl<T: Copy, A: Allocator> Queue for Fixed<T, A> {
         if self.amount == self.capacity() {
             Some(item)
         } else {
-            self.data[self.write_to()].write(item);
+            let write = self.write_to();
+            self.data[write].write(item);
             self.amount += 1;
 
             None
         }
     }
 
+    /// Enqueue an item, overwriting and returning the oldest item if the queue is full, exactly
+    /// like [`Fixed::enqueue_overwrite`].
+    fn enqueue_evicting(&mut self, item: T) -> Option<T> {
+        self.enqueue_overwrite(item)
+    }
+
     /// Expose a non-empty slice of memory for the client code to fill with items that should
     /// be enqueued.
     ///
@@ -145,9 +731,32 @@ impl<T: Copy, A: Allocator> Queue for Fixed<T, A> {
     /// originally uninitialized. Violating the invariants will cause the queue to read undefined
     /// memory, which triggers undefined behavior.
     unsafe fn consider_enqueued(&mut self, amount: usize) {
+        debug_assert!(
+            self.amount + amount <= self.capacity(),
+            "cannot consider more items enqueued than the queue has slots"
+        );
+
         self.amount += amount;
     }
 
+    /// Enqueue a non-zero number of items by reading them from a given buffer and returning how
+    /// many items were enqueued. Fills the free slots both before and after the end of the
+    /// ring-buffer, so that as many items as fit are enqueued in a single call.
+    ///
+    /// Will return `0` if the queue is full at the time of calling.
+    fn bulk_enqueue(&mut self, buffer: &[T]) -> usize {
+        let (first, second) = self.expose_all_slots();
+
+        let first_amount = min(first.len(), buffer.len());
+        first[..first_amount].write_copy_of_slice(&buffer[..first_amount]);
+        let second_amount = min(second.len(), buffer.len() - first_amount);
+        second[..second_amount]
+            .write_copy_of_slice(&buffer[first_amount..first_amount + second_amount]);
+
+        self.amount += first_amount + second_amount;
+        first_amount + second_amount
+    }
+
     /// Attempt to dequeue the next item.
     ///
     /// Will return `None` if the queue is empty at the time of calling.
@@ -155,12 +764,41 @@ impl<T: Copy, A: Allocator> Queue for Fixed<T, A> {
         if self.amount == 0 {
             None
         } else {
+            debug_assert!(self.read < self.capacity(), "read index out of bounds");
+
             let previous_read = self.read;
             // Advance the read index by 1 or reset to 0 if at capacity.
             self.read = (self.read + 1) % self.capacity();
             self.amount -= 1;
 
-            Some(unsafe { self.data[previous_read].assume_init() })
+            let item = unsafe { self.data[previous_read].assume_init() };
+            self.zero_slots(previous_read, 1);
+
+            Some(item)
+        }
+    }
+
+    /// Return the next item that would be dequeued, without removing it from the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn peek(&self) -> Option<T> {
+        if self.amount == 0 {
+            None
+        } else {
+            Some(unsafe { self.data[self.read].assume_init() })
+        }
+    }
+
+    /// Return the most recently enqueued item, without removing it from the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn last(&self) -> Option<T> {
+        if self.amount == 0 {
+            None
+        } else {
+            Some(unsafe {
+                self.data[(self.read + self.amount - 1) % self.capacity()].assume_init()
+            })
         }
     }
 
@@ -171,7 +809,7 @@ impl<T: Copy, A: Allocator> Queue for Fixed<T, A> {
         if self.amount == 0 {
             None
         } else {
-            Some(unsafe { MaybeUninit::slice_assume_init_ref(self.readable_slice()) })
+            Some(unsafe { self.readable_slice().assume_init_ref() })
         }
     }
 
@@ -182,74 +820,470 @@ impl<T: Copy, A: Allocator> Queue for Fixed<T, A> {
     /// Callers must not mark items as dequeued that had not previously been exposed by
     /// `expose_items`.
     fn consider_dequeued(&mut self, amount: usize) {
-        self.read = (self.read + amount) % self.capacity();
-        self.amount -= amount;
-    }
-}
-
-impl<T: fmt::Debug, A: Allocator> fmt::Debug for Fixed<T, A> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Fixed")
-            .field("capacity", &self.capacity())
-            .field("len", &self.amount)
-            .field_with("data", |f| {
-                let mut list = f.debug_list();
-
-                if self.is_data_contiguous() {
-                    for item in unsafe {
-                        MaybeUninit::slice_assume_init_ref(&self.data[self.read..self.write_to()])
-                    } {
-                        list.entry(item);
-                    }
-                } else {
-                    for item in
-                        unsafe { MaybeUninit::slice_assume_init_ref(&self.data[self.read..]) }
-                    {
-                        list.entry(item);
-                    }
-
-                    for item in unsafe {
-                        MaybeUninit::slice_assume_init_ref(
-                            &self.data[0..(self.amount - self.data[self.read..].len())],
-                        )
-                    } {
-                        list.entry(item);
-                    }
-                }
+        debug_assert!(
+            amount <= self.amount,
+            "cannot consider more items dequeued than the queue contains"
+        );
 
-                list.finish()
-            })
-            .finish()
+        if amount > 0 {
+            self.zero_slots(self.read, amount);
+            self.read = (self.read + amount) % self.capacity();
+            self.amount -= amount;
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use alloc::format;
+    /// Dequeue a non-zero number of items by writing them into a given buffer and returning how
+    /// many items were dequeued. Reads the items both before and after the end of the
+    /// ring-buffer, so that as many items as fit into the buffer are dequeued in a single call.
+    ///
+    /// Will return `0` if the queue is empty at the time of calling.
+    fn bulk_dequeue(&mut self, buffer: &mut [T]) -> usize {
+        let (head, tail) = self.as_slices();
 
-    use super::*;
+        let head_amount = min(head.len(), buffer.len());
+        buffer[..head_amount].copy_from_slice(&head[..head_amount]);
+        let tail_amount = min(tail.len(), buffer.len() - head_amount);
+        buffer[head_amount..head_amount + tail_amount].copy_from_slice(&tail[..tail_amount]);
 
-    #[test]
-    fn enqueues_and_dequeues_with_correct_amount() {
-        let mut queue: Fixed<u8> = Fixed::new(4);
+        self.consider_dequeued(head_amount + tail_amount);
+        head_amount + tail_amount
+    }
 
-        assert_eq!(queue.enqueue(7), None);
-        assert_eq!(queue.enqueue(21), None);
-        assert_eq!(queue.enqueue(196), None);
-        assert_eq!(queue.len(), 3);
+    /// Dequeue a non-zero number of items by writing them into a given buffer of possible
+    /// uninitialised memory and returning how many items were dequeued. Reads the items both
+    /// before and after the end of the ring-buffer, so that as many items as fit into the buffer
+    /// are dequeued in a single call.
+    ///
+    /// Will return `0` if the queue is empty at the time of calling.
+    fn bulk_dequeue_uninit(&mut self, buffer: &mut [MaybeUninit<T>]) -> usize {
+        let (head, tail) = self.as_slices();
 
-        assert_eq!(queue.enqueue(233), None);
-        assert_eq!(queue.len(), 4);
+        let head_amount = min(head.len(), buffer.len());
+        buffer[..head_amount].write_copy_of_slice(&head[..head_amount]);
+        let tail_amount = min(tail.len(), buffer.len() - head_amount);
+        buffer[head_amount..head_amount + tail_amount].write_copy_of_slice(&tail[..tail_amount]);
 
-        // Queue should be first-in, first-out.
-        assert_eq!(queue.dequeue(), Some(7));
-        assert_eq!(queue.len(), 3);
+        self.consider_dequeued(head_amount + tail_amount);
+        head_amount + tail_amount
     }
+}
 
-    #[test]
+/// The iterator returned by [`Fixed::drain`].
+struct Drain<'a, T, A: Allocator> {
+    queue: &'a mut Fixed<T, A>,
+}
+
+impl<T: Copy, A: Allocator> Iterator for Drain<'_, T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.queue.dequeue()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.queue.amount, Some(self.queue.amount))
+    }
+}
+
+impl<T, A: Allocator> Drop for Drain<'_, T, A> {
+    fn drop(&mut self) {
+        // Items are `Copy`, so there is nothing to drop.
+        self.queue.zero_slots(self.queue.read, self.queue.amount);
+        self.queue.read = 0;
+        self.queue.amount = 0;
+    }
+}
+
+impl<T: Copy, A: Allocator> Deque for Fixed<T, A> {
+    /// Attempt to enqueue an item at the front of the queue, so that it is the next item to be dequeued.
+    ///
+    /// Will return the item if the queue is full at the time of calling.
+    fn push_front(&mut self, item: T) -> Option<T> {
+        if self.amount == self.capacity() {
+            Some(item)
+        } else {
+            // Move the read index back by 1 or wrap around to the last slot.
+            self.read = (self.read + self.capacity() - 1) % self.capacity();
+            self.data[self.read].write(item);
+            self.amount += 1;
+
+            None
+        }
+    }
+
+    /// Attempt to remove the most recently enqueued item from the back of the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn pop_back(&mut self) -> Option<T> {
+        if self.amount == 0 {
+            None
+        } else {
+            self.amount -= 1;
+
+            let back = self.write_to();
+            let item = unsafe { self.data[back].assume_init() };
+            self.zero_slots(back, 1);
+
+            Some(item)
+        }
+    }
+}
+
+/// Overwrite the whole ring-buffer with zeroes before freeing it, if this queue was created by
+/// [`Fixed::new_zeroizing`]. Does not drop the items the queue still contains.
+//
+// Zeroing only writes to the memory of the items and never reads them as `T`, so the items may
+// hold references that are already dangling, exactly like without this impl.
+#[cfg(feature = "nightly")]
+unsafe impl<#[may_dangle] T, A: Allocator> Drop for Fixed<T, A> {
+    fn drop(&mut self) {
+        self.zero_slots(0, self.capacity());
+    }
+}
+
+/// Overwrite the whole ring-buffer with zeroes before freeing it, if this queue was created by
+/// [`Fixed::new_zeroizing`]. Does not drop the items the queue still contains.
+//
+// Without the `nightly` feature, `#[may_dangle]` is unavailable, so the items must outlive the
+// queue.
+#[cfg(not(feature = "nightly"))]
+impl<T, A: Allocator> Drop for Fixed<T, A> {
+    fn drop(&mut self) {
+        self.zero_slots(0, self.capacity());
+    }
+}
+
+impl<T: Copy, A: Allocator + Clone> Clone for Fixed<T, A> {
+    /// Create a queue of the same capacity, holding the same items. Only the items currently in the
+    /// queue are copied, into the start of a fresh allocation.
+    fn clone(&self) -> Self {
+        Fixed {
+            data: self.linearized_data(self.capacity()),
+            read: 0,
+            amount: self.amount,
+            zeroizing: self.zeroizing,
+        }
+    }
+
+    /// Replace the items of this queue with copies of the items of `source`. If both queues have
+    /// the same capacity, the allocation of this queue is reused, which makes repeated snapshots
+    /// of a queue cheap. Otherwise, this allocates a fresh ring-buffer, exactly like
+    /// [`clone`](Clone::clone). Either way, the items are stored contiguously afterwards,
+    /// starting at the beginning of the ring-buffer, and this queue is zeroizing if and only if
+    /// `source` is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacities differ and the memory allocation fails.
+    fn clone_from(&mut self, source: &Self) {
+        if self.capacity() != source.capacity() {
+            *self = source.clone();
+        } else {
+            self.clear();
+            let (head, tail) = source.as_slices();
+            self.data[..head.len()].write_copy_of_slice(head);
+            self.data[head.len()..source.amount].write_copy_of_slice(tail);
+            self.amount = source.amount;
+            self.zeroizing = source.zeroizing;
+        }
+    }
+}
+
+/// Collect the items into a queue whose capacity is exactly the number of items.
+impl<T> FromIterator<T> for Fixed<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let items: Vec<T> = iter.into_iter().collect();
+
+        let mut queue = Fixed::new(items.len());
+        for (slot, item) in queue.data.iter_mut().zip(items) {
+            slot.write(item);
+        }
+        queue.amount = queue.capacity();
+
+        queue
+    }
+}
+
+/// Turn a vector into a full queue whose capacity is exactly the length of the vector, with the
+/// first item of the vector at the front of the queue. Reuses the allocation of the vector, after
+/// shrinking it to fit.
+impl<T> From<Vec<T>> for Fixed<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let amount = vec.len();
+        let items = Box::into_raw(vec.into_boxed_slice());
+
+        Fixed {
+            // `MaybeUninit<T>` has the same layout as `T`.
+            data: allocator::from_box(unsafe { Box::from_raw(items as *mut [MaybeUninit<T>]) }),
+            read: 0,
+            amount,
+            zeroizing: false,
+        }
+    }
+}
+
+/// Move all items of a queue into a vector, in FIFO order.
+impl<T, A: Allocator> From<Fixed<T, A>> for Vec<T> {
+    fn from(queue: Fixed<T, A>) -> Self {
+        let (head, tail) = queue.live_slices();
+
+        let mut vec = Vec::with_capacity(queue.amount);
+        for slot in head.iter().chain(tail) {
+            // The queue never drops its items, so each item is moved out exactly once.
+            vec.push(unsafe { slot.assume_init_read() });
+        }
+
+        // Dropping the queue zeroes its slots if it is zeroizing.
+        vec
+    }
+}
+
+/// Turn a double-ended queue into a full queue whose capacity is exactly its length, preserving
+/// FIFO order.
+#[cfg(feature = "std")]
+impl<T> From<std::collections::VecDeque<T>> for Fixed<T> {
+    fn from(deque: std::collections::VecDeque<T>) -> Self {
+        Fixed::from(Vec::from(deque))
+    }
+}
+
+/// Move all items of a queue into a double-ended queue, in FIFO order.
+#[cfg(feature = "std")]
+impl<T, A: Allocator> From<Fixed<T, A>> for std::collections::VecDeque<T> {
+    fn from(queue: Fixed<T, A>) -> Self {
+        Vec::from(queue).into()
+    }
+}
+
+/// Enqueue items until the queue is full. Once the queue is full, all further items are
+/// silently discarded. Use [`Fixed::extend_counted`] to learn how many items were enqueued.
+impl<T: Copy, A: Allocator> Extend<T> for Fixed<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend_counted(iter);
+    }
+}
+
+/// Queues are equal if they contain equal items in the same order, regardless of their capacities.
+impl<T: PartialEq, A: Allocator, B: Allocator> PartialEq<Fixed<T, B>> for Fixed<T, A> {
+    fn eq(&self, other: &Fixed<T, B>) -> bool {
+        let (head, tail) = self.as_slices();
+        let (other_head, other_tail) = other.as_slices();
+
+        self.amount == other.amount
+            && head
+                .iter()
+                .chain(tail)
+                .eq(other_head.iter().chain(other_tail))
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for Fixed<T, A> {}
+
+/// Hashes the number of items, followed by the items in FIFO order, consistent with [`PartialEq`].
+impl<T: Hash, A: Allocator> Hash for Fixed<T, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let (head, tail) = self.as_slices();
+
+        self.amount.hash(state);
+        for item in head.iter().chain(tail) {
+            item.hash(state);
+        }
+    }
+}
+
+/// Queues are equal if they contain equal items in the same order, regardless of their capacities.
+impl<T: PartialEq, A: Allocator, const N: usize> PartialEq<Static<T, N>> for Fixed<T, A> {
+    fn eq(&self, other: &Static<T, N>) -> bool {
+        let (head, tail) = self.as_slices();
+        let (other_head, other_tail) = other.as_slices();
+
+        self.amount == other_head.len() + other_tail.len()
+            && head
+                .iter()
+                .chain(tail)
+                .eq(other_head.iter().chain(other_tail))
+    }
+}
+
+/// Queues are equal if they contain equal items in the same order, regardless of their capacities.
+impl<T: PartialEq, A: Allocator, const N: usize> PartialEq<Fixed<T, A>> for Static<T, N> {
+    fn eq(&self, other: &Fixed<T, A>) -> bool {
+        other == self
+    }
+}
+
+impl<T, A: Allocator> Index<usize> for Fixed<T, A> {
+    type Output = T;
+
+    /// Return a reference to the item at the given position, counted from the front of the queue.
+    ///
+    /// Panics if `index >= self.len()`.
+    fn index(&self, index: usize) -> &T {
+        assert!(
+            index < self.amount,
+            "index out of bounds: the len is {} but the index is {}",
+            self.amount,
+            index
+        );
+
+        unsafe { self.data[(self.read + index) % self.capacity()].assume_init_ref() }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A: Allocator> Fixed<u8, A> {
+    /// Read bytes from a reader directly into the free slots of the queue, without an
+    /// intermediate buffer, and return how many bytes were read and enqueued. Performs a single
+    /// call to [`read`](std::io::Read::read), so at most the first contiguous segment of free
+    /// slots is filled; call this repeatedly to fill the whole queue.
+    ///
+    /// Will return `Ok(0)` if the queue is full, or if the reader has reached its end. Errors of
+    /// the reader are returned unchanged, and leave the queue unchanged.
+    pub fn fill_from_read<R: std::io::Read>(&mut self, r: &mut R) -> std::io::Result<usize> {
+        match self.expose_slots() {
+            None => Ok(0),
+            Some(slots) => {
+                // `Read::read` requires initialised memory, so zero the slots first.
+                slots.fill(MaybeUninit::new(0));
+                let slots = unsafe { slots.assume_init_mut() };
+                // A faulty reader might report having read more bytes than fit into the slots.
+                let amount = min(r.read(slots)?, slots.len());
+
+                unsafe {
+                    self.consider_enqueued(amount);
+                }
+
+                Ok(amount)
+            }
+        }
+    }
+
+    /// Write bytes from the front of the queue directly to a writer, without an intermediate
+    /// buffer, and return how many bytes were written and dequeued. Performs a single call to
+    /// [`write`](std::io::Write::write), so at most the first contiguous segment of items is
+    /// written; call this repeatedly to drain the whole queue.
+    ///
+    /// Exactly as many bytes are dequeued as the writer reports having written, so after a
+    /// short write the remaining bytes stay in the queue. Will return `Ok(0)` if the queue is
+    /// empty. Errors of the writer are returned unchanged, and leave the queue unchanged.
+    pub fn drain_to_write<W: std::io::Write>(&mut self, w: &mut W) -> std::io::Result<usize> {
+        match self.expose_items() {
+            None => Ok(0),
+            Some(items) => {
+                // A faulty writer might report having written more bytes than it was given.
+                let amount = min(w.write(items)?, items.len());
+                self.consider_dequeued(amount);
+
+                Ok(amount)
+            }
+        }
+    }
+}
+
+/// Enqueues the written bytes. Writing to a full queue returns `Ok(0)`.
+#[cfg(feature = "std")]
+impl<A: Allocator> std::io::Write for Fixed<u8, A> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(self.bulk_enqueue(buf))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Dequeues the read bytes. Reading from an empty queue returns `Ok(0)`.
+#[cfg(feature = "std")]
+impl<A: Allocator> std::io::Read for Fixed<u8, A> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        Ok(self.bulk_dequeue(buf))
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for Fixed<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (head, tail) = self.as_slices();
+
+        f.debug_struct("Fixed")
+            .field("capacity", &self.capacity())
+            .field("len", &self.amount)
+            .field("data", &DebugItems(head, tail))
+            .finish()
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator + fmt::Debug> Fixed<T, A> {
+    /// Return a value whose [`Debug`](fmt::Debug) output is that of the queue, plus an `alloc`
+    /// field with the allocator of the queue.
+    pub fn debug_with_alloc(&self) -> impl fmt::Debug + '_ {
+        DebugWithAlloc(self)
+    }
+}
+
+struct DebugWithAlloc<'a, T, A: Allocator>(&'a Fixed<T, A>);
+
+impl<T: fmt::Debug, A: Allocator + fmt::Debug> fmt::Debug for DebugWithAlloc<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let queue = self.0;
+        let (head, tail) = queue.as_slices();
+
+        f.debug_struct("Fixed")
+            .field("capacity", &queue.capacity())
+            .field("len", &queue.amount)
+            .field("data", &DebugItems(head, tail))
+            .field("alloc", &allocator::allocator(&queue.data))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "nightly")]
+    use alloc::alloc::{AllocError, Layout};
+    use alloc::format;
+
+    #[cfg(feature = "nightly")]
+    use core::ptr::NonNull;
+
+    use super::*;
+
+    /// An allocator that fails every allocation.
+    #[cfg(feature = "nightly")]
+    #[derive(Debug)]
+    struct FailingAllocator;
+
+    #[cfg(feature = "nightly")]
+    unsafe impl Allocator for FailingAllocator {
+        fn allocate(&self, _layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            Err(AllocError)
+        }
+
+        unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {
+            unreachable!("FailingAllocator never allocates")
+        }
+    }
+
+    #[test]
+    fn enqueues_and_dequeues_with_correct_amount() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.enqueue(21), None);
+        assert_eq!(queue.enqueue(196), None);
+        assert_eq!(queue.len(), 3);
+
+        assert_eq!(queue.enqueue(233), None);
+        assert_eq!(queue.len(), 4);
+
+        // Queue should be first-in, first-out.
+        assert_eq!(queue.dequeue(), Some(7));
+        assert_eq!(queue.len(), 3);
+    }
+
+    #[test]
     fn bulk_enqueues_and_dequeues_with_correct_amount() {
         let mut queue: Fixed<u8> = Fixed::new(4);
-        let mut buf: [MaybeUninit<u8>; 4] = MaybeUninit::uninit_array();
+        let mut buf: [MaybeUninit<u8>; 4] = [MaybeUninit::uninit(); 4];
 
         let enqueue_amount = queue.bulk_enqueue(b"ufo");
         let dequeue_amount = queue.bulk_dequeue_uninit(&mut buf);
@@ -286,14 +1320,14 @@ mod tests {
         // Copy data to two of the available slots and call `consider_queued`.
         let data = b"tofu";
         let slots = queue.expose_slots().unwrap();
-        MaybeUninit::copy_from_slice(&mut slots[0..2], &data[0..2]);
+        slots[0..2].write_copy_of_slice(&data[0..2]);
         unsafe {
             queue.consider_enqueued(2);
         }
 
         // Copy data to two of the available slots and call `consider_queued`.
         let slots = queue.expose_slots().unwrap();
-        MaybeUninit::copy_from_slice(&mut slots[0..2], &data[0..2]);
+        slots[0..2].write_copy_of_slice(&data[0..2]);
         unsafe {
             queue.consider_enqueued(2);
         }
@@ -318,45 +1352,1838 @@ mod tests {
     }
 
     #[test]
-    fn test_debug_impl() {
-        let mut queue: Fixed<u8> = Fixed::new(4);
+    fn returns_none_on_peek_when_queue_is_empty() {
+        let mut queue: Fixed<u8> = Fixed::new(1);
+
+        assert_eq!(queue.peek(), None);
+
+        // Enqueue and then dequeue an item.
+        let _ = queue.enqueue(7);
+        let _ = queue.dequeue();
+
+        // The queue is now empty again.
+        assert_eq!(queue.peek(), None);
+    }
+
+    #[test]
+    fn peek_returns_the_item_that_is_dequeued_next() {
+        let mut queue: Fixed<u8> = Fixed::new(3);
 
         assert_eq!(queue.enqueue(7), None);
         assert_eq!(queue.enqueue(21), None);
-        assert_eq!(queue.enqueue(196), None);
-        assert_eq!(
-            format!("{:?}", queue),
-            "Fixed { capacity: 4, len: 3, data: [7, 21, 196] }"
-        );
 
-        assert_eq!(queue.dequeue(), Some(7));
-        assert_eq!(
-            format!("{:?}", queue),
-            "Fixed { capacity: 4, len: 2, data: [21, 196] }"
-        );
+        // Peeking does not remove the item.
+        assert_eq!(queue.peek(), Some(7));
+        assert_eq!(queue.peek(), Some(7));
+        assert_eq!(queue.len(), 2);
 
+        assert_eq!(queue.dequeue(), Some(7));
+        assert_eq!(queue.peek(), Some(21));
         assert_eq!(queue.dequeue(), Some(21));
-        assert_eq!(
-            format!("{:?}", queue),
-            "Fixed { capacity: 4, len: 1, data: [196] }"
-        );
+    }
 
-        assert_eq!(queue.enqueue(33), None);
-        assert_eq!(
-            format!("{:?}", queue),
-            "Fixed { capacity: 4, len: 2, data: [196, 33] }"
-        );
+    #[test]
+    fn peeks_correctly_after_wraparound() {
+        let mut queue: Fixed<u8> = Fixed::new(3);
 
-        assert_eq!(queue.enqueue(17), None);
-        assert_eq!(
-            format!("{:?}", queue),
-            "Fixed { capacity: 4, len: 3, data: [196, 33, 17] }"
-        );
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
 
-        assert_eq!(queue.enqueue(200), None);
-        assert_eq!(
-            format!("{:?}", queue),
-            "Fixed { capacity: 4, len: 4, data: [196, 33, 17, 200] }"
+        // This item is stored at the start of the ring-buffer.
+        assert_eq!(queue.enqueue(4), None);
+        assert_eq!(queue.peek(), Some(3));
+        assert_eq!(queue.dequeue(), Some(3));
+
+        // The read index has wrapped around.
+        assert_eq!(queue.peek(), Some(4));
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.peek(), None);
+    }
+
+    #[test]
+    fn try_new_in_creates_queue_with_working_allocator() {
+        let mut queue: Fixed<u8> = Fixed::try_new_in(4, Global).unwrap();
+
+        assert_eq!(queue.capacity(), 4);
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.dequeue(), Some(7));
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn try_new_in_returns_none_when_allocation_fails() {
+        let queue: Option<Fixed<u8, FailingAllocator>> = Fixed::try_new_in(4, FailingAllocator);
+
+        assert!(queue.is_none());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "cannot consider more items dequeued than the queue contains")]
+    fn panics_on_considering_too_many_items_dequeued() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        queue.consider_dequeued(4);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "cannot consider more items enqueued than the queue has slots")]
+    fn panics_on_considering_too_many_items_enqueued() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        unsafe {
+            queue.consider_enqueued(2);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "read index out of bounds")]
+    fn panics_on_dequeue_with_corrupted_read_index() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+
+        assert_eq!(queue.enqueue(7), None);
+        queue.read = 4;
+        let _ = queue.dequeue();
+    }
+
+    #[test]
+    fn reports_capacity_and_remaining() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(Queue::capacity(&queue), 4);
+        assert_eq!(queue.remaining(), 4);
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(Queue::capacity(&queue), 4);
+        assert_eq!(queue.remaining(), 1);
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.remaining(), 0);
+
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        assert_eq!(queue.remaining(), 1);
+    }
+
+    #[test]
+    fn iterates_over_items_without_dequeueing() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.iter().next(), None);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert!(queue.iter().eq([1, 2, 3]));
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.dequeue(), Some(1));
+    }
+
+    #[test]
+    fn iterates_over_wrapped_items() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        // These items are stored at the start of the ring-buffer.
+        assert_eq!(queue.enqueue(5), None);
+        assert_eq!(queue.enqueue(6), None);
+
+        assert!(queue.iter().eq([4, 5, 6]));
+    }
+
+    #[test]
+    fn as_slices_of_contiguous_items() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.as_slices(), (&[][..], &[][..]));
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.as_slices(), (&[2, 3][..], &[][..]));
+    }
+
+    #[test]
+    fn as_slices_of_wrapped_items() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.enqueue(5), None);
+        assert_eq!(queue.as_slices(), (&[3, 4][..], &[5][..]));
+
+        // Completely full, with the read index in the middle of the ring-buffer.
+        assert_eq!(queue.enqueue(6), None);
+        assert_eq!(queue.as_slices(), (&[3, 4][..], &[5, 6][..]));
+    }
+
+    #[test]
+    fn transfers_limited_number_of_items() {
+        let mut source: Static<u8, 4> = Static::new();
+        let mut queue: Fixed<u8> = Fixed::new(5);
+
+        assert_eq!(source.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(source.dequeue(), Some(b'u'));
+        // The source items wrap around the end of its ring-buffer.
+        assert_eq!(source.bulk_enqueue(b"to"), 2);
+        assert_eq!(queue.bulk_enqueue(b"fu"), 2);
+
+        assert_eq!(crate::transfer(&mut source, &mut queue, 3), 3);
+        assert!(source.iter().eq(*b"o"));
+        assert!(queue.iter().eq(*b"fufot"));
+
+        // Stops once the destination is full.
+        assert_eq!(crate::transfer(&mut source, &mut queue, 3), 0);
+        assert_eq!(queue.dequeue(), Some(b'f'));
+        assert_eq!(crate::transfer(&mut source, &mut queue, 3), 1);
+        assert!(source.is_empty());
+    }
+
+    #[test]
+    fn bulk_enqueues_from_other_queue() {
+        let mut source: Static<u8, 4> = Static::new();
+        let mut queue: Fixed<u8> = Fixed::new(3);
+
+        // Nothing to move out of an empty queue.
+        assert_eq!(queue.bulk_enqueue_from(&mut source), 0);
+
+        assert_eq!(source.bulk_enqueue(b"tofu"), 4);
+        assert_eq!(queue.bulk_enqueue_from(&mut source), 3);
+        assert_eq!(source.len(), 1);
+        assert_eq!(queue.len(), 3);
+
+        // No slots left to move into.
+        assert_eq!(queue.bulk_enqueue_from(&mut source), 0);
+
+        assert_eq!(queue.dequeue(), Some(b't'));
+        assert_eq!(queue.bulk_enqueue_from(&mut source), 1);
+        assert!(source.is_empty());
+
+        let mut buf = [0; 3];
+        assert_eq!(queue.bulk_dequeue(&mut buf), 3);
+        assert_eq!(&buf, b"ofu");
+    }
+
+    #[test]
+    fn enqueue_overwrite_keeps_newest_items() {
+        let mut queue: Fixed<u8> = Fixed::new(3);
+
+        assert_eq!(queue.enqueue_overwrite(1), None);
+        assert_eq!(queue.enqueue_overwrite(2), None);
+        assert_eq!(queue.enqueue_overwrite(3), None);
+
+        assert_eq!(queue.enqueue_overwrite(4), Some(1));
+        assert_eq!(queue.enqueue_overwrite(5), Some(2));
+        assert_eq!(queue.len(), 3);
+        assert!(queue.iter().eq([3, 4, 5]));
+
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.enqueue_overwrite(6), None);
+        assert_eq!(queue.enqueue_overwrite(7), Some(4));
+
+        assert_eq!(queue.dequeue(), Some(5));
+        assert_eq!(queue.dequeue(), Some(6));
+        assert_eq!(queue.dequeue(), Some(7));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn clones_are_independent() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(5), None);
+        assert_eq!(queue.enqueue(6), None);
+
+        let mut clone = queue.clone();
+        assert_eq!(clone.capacity(), 4);
+        assert_eq!(clone.len(), 4);
+
+        for item in 3..=6 {
+            assert_eq!(queue.dequeue(), Some(item));
+        }
+        assert_eq!(queue.dequeue(), None);
+
+        assert_eq!(clone.enqueue(7), Some(7));
+        for item in 3..=6 {
+            assert_eq!(clone.dequeue(), Some(item));
+        }
+        assert_eq!(clone.dequeue(), None);
+    }
+
+    #[test]
+    fn compares_contents_regardless_of_layout() {
+        let mut wrapped: Fixed<u8> = Fixed::new(4);
+        assert_eq!(wrapped.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(wrapped.dequeue(), Some(1));
+        assert_eq!(wrapped.dequeue(), Some(2));
+        assert_eq!(wrapped.enqueue(5), None);
+
+        let mut contiguous: Fixed<u8> = Fixed::new(3);
+        assert_eq!(contiguous.bulk_enqueue(&[3, 4, 5]), 3);
+        assert_eq!(wrapped, contiguous);
+
+        let mut other: Static<u8, 8> = Static::new();
+        assert_eq!(other.bulk_enqueue(&[3, 4, 5]), 3);
+        assert_eq!(wrapped, other);
+        assert_eq!(other, wrapped);
+
+        assert_eq!(contiguous.dequeue(), Some(3));
+        assert_ne!(wrapped, contiguous);
+        assert_eq!(contiguous.enqueue(6), None);
+        assert_ne!(wrapped, contiguous);
+        assert_ne!(Fixed::<u8>::new(2), wrapped);
+        assert_eq!(Fixed::<u8>::new(2), Fixed::<u8>::new(4));
+    }
+
+    #[test]
+    fn collects_into_exactly_fitting_queue() {
+        let mut queue: Fixed<u8> = (0..10).collect();
+        assert_eq!(queue.capacity(), 10);
+        assert_eq!(queue.len(), 10);
+        assert_eq!(queue.enqueue(10), Some(10));
+
+        for item in 0..10 {
+            assert_eq!(queue.dequeue(), Some(item));
+        }
+        assert_eq!(queue.dequeue(), None);
+
+        let empty: Fixed<u8> = core::iter::empty().collect();
+        assert_eq!(empty.capacity(), 0);
+    }
+
+    #[test]
+    fn extends_until_full() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+
+        queue.extend([1, 2]);
+        assert!(queue.iter().eq([1, 2]));
+
+        // Items that do not fit are discarded.
+        queue.extend([3, 4, 5, 6]);
+        assert_eq!(queue.len(), 4);
+        assert!(queue.iter().eq([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn extend_counted_reports_enqueued_items() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        let mut items = 1..=6;
+
+        assert_eq!(queue.extend_counted(items.by_ref().take(2)), 2);
+        assert_eq!(queue.extend_counted(items.by_ref()), 2);
+        assert!(queue.iter().eq([1, 2, 3, 4]));
+
+        // Items that could not be enqueued are left in the iterator.
+        assert!(items.eq([5, 6]));
+        assert_eq!(queue.extend_counted([7]), 0);
+    }
+
+    #[test]
+    fn last_tracks_newest_item() {
+        let mut queue: Fixed<u8> = Fixed::new(3);
+        assert_eq!(queue.last(), None);
+
+        assert_eq!(queue.enqueue(1), None);
+        assert_eq!(queue.last(), Some(1));
+        assert_eq!(queue.enqueue(2), None);
+        assert_eq!(queue.enqueue(3), None);
+        assert_eq!(queue.last(), Some(3));
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.last(), Some(3));
+
+        // This item is stored at the start of the ring-buffer.
+        assert_eq!(queue.enqueue(4), None);
+        assert_eq!(queue.last(), Some(4));
+
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.last(), Some(4));
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.last(), None);
+    }
+
+    #[test]
+    fn mixes_front_and_back_operations() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.pop_back(), None);
+
+        assert_eq!(queue.enqueue(2), None);
+        // Wraps around to the end of the ring-buffer.
+        assert_eq!(queue.push_front(1), None);
+        assert_eq!(queue.enqueue(3), None);
+        assert_eq!(queue.push_front(0), None);
+        assert!(queue.iter().eq([0, 1, 2, 3]));
+
+        assert_eq!(queue.push_front(7), Some(7));
+        assert_eq!(queue.enqueue(7), Some(7));
+
+        assert_eq!(queue.pop_back(), Some(3));
+        assert_eq!(queue.dequeue(), Some(0));
+        assert_eq!(queue.pop_back(), Some(2));
+        assert_eq!(queue.push_front(5), None);
+        assert_eq!(queue.enqueue(6), None);
+        assert!(queue.iter().eq([5, 1, 6]));
+
+        assert_eq!(queue.pop_back(), Some(6));
+        assert_eq!(queue.pop_back(), Some(1));
+        assert_eq!(queue.pop_back(), Some(5));
+        assert_eq!(queue.pop_back(), None);
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn grow_preserves_wrapped_items() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(5), None);
+        assert_eq!(queue.enqueue(6), None);
+
+        queue.grow(6);
+        assert_eq!(queue.capacity(), 6);
+        assert!(queue.iter().eq([3, 4, 5, 6]));
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.enqueue(8), None);
+        assert_eq!(queue.enqueue(9), Some(9));
+        for item in 3..=8 {
+            assert_eq!(queue.dequeue(), Some(item));
+        }
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn grow_to_same_capacity_is_allowed() {
+        let mut queue: Fixed<u8> = Fixed::new(2);
+        assert_eq!(queue.enqueue(1), None);
+
+        queue.grow(2);
+        assert_eq!(queue.capacity(), 2);
+        assert_eq!(queue.dequeue(), Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot grow a Fixed queue to a smaller capacity")]
+    fn grow_panics_on_smaller_capacity() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        queue.grow(3);
+    }
+
+    #[test]
+    fn shrink_to_fit_reduces_capacity_to_length() {
+        let mut queue: Fixed<u8> = Fixed::new(8);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4, 5, 6, 7, 8]), 8);
+        for item in 1..=5 {
+            assert_eq!(queue.dequeue(), Some(item));
+        }
+        assert_eq!(queue.enqueue(9), None);
+
+        queue.shrink_to_fit();
+        assert_eq!(queue.capacity(), 4);
+        assert!(queue.iter().eq([6, 7, 8, 9]));
+        assert_eq!(queue.enqueue(10), Some(10));
+
+        // Already minimal, so this is a no-op.
+        queue.shrink_to_fit();
+        assert_eq!(queue.capacity(), 4);
+        assert!(queue.iter().eq([6, 7, 8, 9]));
+
+        for item in 6..=9 {
+            assert_eq!(queue.dequeue(), Some(item));
+        }
+        queue.shrink_to_fit();
+        assert_eq!(queue.capacity(), 0);
+    }
+
+    #[test]
+    fn make_contiguous_rearranges_wrapped_items() {
+        let mut queue: Fixed<u8> = Fixed::new(5);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4, 5]), 5);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(6), None);
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.as_slices(), (&[4, 5][..], &[6, 7][..]));
+
+        assert_eq!(queue.make_contiguous(), &[4, 5, 6, 7]);
+        assert_eq!(queue.as_slices(), (&[4, 5, 6, 7][..], &[][..]));
+        assert_eq!(queue.expose_items(), Some(&[4, 5, 6, 7][..]));
+
+        // The queue keeps working normally afterwards.
+        assert_eq!(queue.enqueue(8), None);
+        assert_eq!(queue.enqueue(9), Some(9));
+        for item in 4..=8 {
+            assert_eq!(queue.dequeue(), Some(item));
+        }
+        assert_eq!(queue.make_contiguous(), &[]);
+    }
+
+    #[test]
+    fn partial_drain_empties_queue() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.enqueue(5), None);
+
+        let mut drain = queue.drain();
+        assert_eq!(drain.size_hint(), (4, Some(4)));
+        assert_eq!(drain.next(), Some(2));
+        assert_eq!(drain.next(), Some(3));
+        drop(drain);
+
+        assert!(queue.is_empty());
+        assert_eq!(queue.capacity(), 4);
+        assert_eq!(queue.bulk_enqueue(&[6, 7, 8, 9]), 4);
+        assert!(queue.iter().eq([6, 7, 8, 9]));
+    }
+
+    #[test]
+    fn full_drain_yields_all_items() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert!(queue.drain().eq([1, 2, 3]));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn dequeues_all_items_into_vec() {
+        use crate::QueueExt;
+
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.dequeue_all(), []);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(5), None);
+        assert_eq!(queue.enqueue(6), None);
+
+        assert_eq!(queue.dequeue_all(), [3, 4, 5, 6]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn dequeues_up_to_n_items_into_vec() {
+        use crate::QueueExt;
+
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.enqueue(5), None);
+
+        assert_eq!(queue.dequeue_up_to(3), [2, 3, 4]);
+        assert_eq!(queue.dequeue_up_to(3), [5]);
+        assert_eq!(queue.dequeue_up_to(3), []);
+    }
+
+    #[test]
+    fn gets_items_by_index_across_the_wrap_boundary() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.get(0), None);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(5), None);
+        assert_eq!(queue.enqueue(6), None);
+
+        assert_eq!(queue.get(0), Some(3));
+        assert_eq!(queue.get(1), Some(4));
+        assert_eq!(queue.get(2), Some(5));
+        assert_eq!(queue.get(3), Some(6));
+        assert_eq!(queue.get(4), None);
+
+        assert_eq!(queue[0], 3);
+        assert_eq!(queue[3], 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn index_panics_when_out_of_range() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.enqueue(1), None);
+
+        let _ = queue[1];
+    }
+
+    #[test]
+    fn retains_items_in_order_in_wrapped_queue() {
+        let mut queue: Fixed<u8> = Fixed::new(6);
+        assert_eq!(queue.bulk_enqueue(&[0, 0, 0, 1, 2, 3]), 6);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[4, 5, 6]), 3);
+
+        queue.retain(|item| item % 2 == 0);
+        assert_eq!(queue.len(), 3);
+        assert!(queue.iter().eq([2, 4, 6]));
+
+        // The freed slots can be used again.
+        assert_eq!(queue.bulk_enqueue(&[7, 8, 9]), 3);
+        assert!(queue.iter().eq([2, 4, 6, 7, 8, 9]));
+
+        queue.retain(|_| false);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn is_full_flips_across_enqueue_and_dequeue() {
+        let mut queue: Fixed<u8> = Fixed::new(2);
+        assert!(!queue.is_full());
+
+        assert_eq!(queue.enqueue(1), None);
+        assert!(!queue.is_full());
+        assert_eq!(queue.enqueue(2), None);
+        assert!(queue.is_full());
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert!(!queue.is_full());
+        assert_eq!(queue.enqueue(3), None);
+        assert!(queue.is_full());
+    }
+
+    #[test]
+    fn bulk_enqueue_remainder_returns_unaccepted_tail() {
+        let mut queue: Fixed<u8> = Fixed::new(6);
+        assert_eq!(queue.bulk_enqueue(&[0, 0, 0, 0]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 2]), 2);
+        // Leaves room for 4 items, which wrap around the end of the ring-buffer.
+
+        let items = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        assert_eq!(queue.bulk_enqueue_remainder(&items), &items[4..]);
+        assert!(queue.iter().eq([0, 0, 1, 2, 3, 4]));
+
+        assert_eq!(queue.bulk_enqueue_remainder(&items), &items[..]);
+        assert_eq!(queue.dequeue(), Some(0));
+        assert_eq!(queue.bulk_enqueue_remainder(&items[..1]), &[]);
+    }
+
+    #[test]
+    fn fills_all_slots_in_one_pass() {
+        let mut queue: Fixed<u8> = Fixed::new(6);
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 2]), 2);
+
+        let (first, second) = queue.expose_all_slots();
+        assert_eq!((first.len(), second.len()), (2, 2));
+        first.write_copy_of_slice(&[5, 6]);
+        second.write_copy_of_slice(&[7, 8]);
+        unsafe {
+            queue.consider_enqueued(4);
+        }
+        assert!(queue.iter().eq([3, 4, 5, 6, 7, 8]));
+
+        let (first, second) = queue.expose_all_slots();
+        assert_eq!((first.len(), second.len()), (0, 0));
+
+        // Free slots between the last item and the front of the queue are contiguous.
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+        let (first, second) = queue.expose_all_slots();
+        assert_eq!((first.len(), second.len()), (3, 0));
+        first[0].write(9);
+        unsafe {
+            queue.consider_enqueued(1);
+        }
+        assert!(queue.iter().eq([6, 7, 8, 9]));
+    }
+
+    /// Return whether every slot of the ring-buffer that holds no item contains only zeroes. Zeroizing
+    /// queues start out zeroed, so this never reads uninitialized memory.
+    fn free_slots_are_zeroed(queue: &Fixed<u8>) -> bool {
+        let capacity = queue.capacity();
+        (queue.amount..capacity).all(|i| {
+            let slot = queue.data[(queue.read + i) % capacity].as_ptr();
+            unsafe { slot.read() == 0 }
+        })
+    }
+
+    #[test]
+    fn zeroizing_queue_zeroes_freed_slots() {
+        let mut queue: Fixed<u8> = Fixed::new_zeroizing(4);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert!(free_slots_are_zeroed(&queue));
+
+        assert_eq!(queue.bulk_dequeue(&mut [0; 2]), 2);
+        assert!(free_slots_are_zeroed(&queue));
+
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[5, 6, 7]), 3);
+        queue.retain(|item| item % 2 == 0);
+        assert!(queue.iter().eq([4, 6]));
+        assert!(free_slots_are_zeroed(&queue));
+
+        queue.rotate_left(1);
+        assert!(free_slots_are_zeroed(&queue));
+        queue.rotate_right(1);
+        assert!(free_slots_are_zeroed(&queue));
+
+        drop(queue.drain());
+        assert!(free_slots_are_zeroed(&queue));
+
+        // Clones remain zeroizing.
+        assert_eq!(queue.enqueue(8), None);
+        let mut clone = queue.clone();
+        assert_eq!(clone.dequeue(), Some(8));
+        assert!(free_slots_are_zeroed(&clone));
+    }
+
+    /// An allocator that counts how many of the allocations it frees were not all zeroes.
+    #[cfg(feature = "nightly")]
+    #[derive(Debug, Clone, Copy)]
+    struct ZeroCheckingAllocator<'a> {
+        nonzero_deallocations: &'a core::cell::Cell<usize>,
+    }
+
+    #[cfg(feature = "nightly")]
+    unsafe impl Allocator for ZeroCheckingAllocator<'_> {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            let memory = unsafe { core::slice::from_raw_parts(ptr.as_ptr(), layout.size()) };
+            if memory.iter().any(|byte| *byte != 0) {
+                self.nonzero_deallocations
+                    .set(self.nonzero_deallocations.get() + 1);
+            }
+
+            unsafe { Global.deallocate(ptr, layout) }
+        }
+    }
+
+    #[test]
+    fn zeroizing_queue_zeroes_popped_slots() {
+        let mut queue: Fixed<u8> = Fixed::new_zeroizing(4);
+
+        assert_eq!(queue.bulk_enqueue(&[0xAA, 0xAA, 0xBB]), 3);
+        assert_eq!(queue.pop_back(), Some(0xBB));
+        assert!(free_slots_are_zeroed(&queue));
+
+        assert_eq!(queue.dequeue(), Some(0xAA));
+        // This item wraps around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[0xBB, 0xBB, 0xBB]), 3);
+        assert_eq!(queue.pop_back(), Some(0xBB));
+        assert!(free_slots_are_zeroed(&queue));
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn zeroizing_queue_zeroes_memory_before_freeing_it() {
+        let nonzero_deallocations = core::cell::Cell::new(0);
+        let alloc = ZeroCheckingAllocator {
+            nonzero_deallocations: &nonzero_deallocations,
+        };
+        let zeroizing = |capacity| Fixed {
+            data: Box::new_zeroed_slice_in(capacity, alloc),
+            read: 0,
+            amount: 0,
+            zeroizing: true,
+        };
+
+        let mut queue = zeroizing(4);
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        queue.grow(8);
+        queue.shrink_to_fit();
+        assert_eq!(queue.capacity(), 3);
+        drop(queue);
+
+        let mut queue = zeroizing(4);
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert_eq!(Vec::from(queue), [1, 2, 3]);
+        assert_eq!(nonzero_deallocations.get(), 0);
+
+        // Queues that are not zeroizing leave their items in memory.
+        let mut queue = Fixed::new_in(4, alloc);
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        drop(queue);
+        assert_eq!(nonzero_deallocations.get(), 1);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn items_may_borrow_values_that_are_dropped_before_the_queue() {
+        let mut queue: Fixed<&u32> = Fixed::new(2);
+        let mut zeroizing: Fixed<&u32> = Fixed::new_zeroizing(2);
+
+        // Declared after the queues, so dropped before them.
+        let item = 5;
+        assert_eq!(queue.enqueue(&item), None);
+        assert_eq!(zeroizing.enqueue(&item), None);
+        assert_eq!(queue.peek(), Some(&5));
+    }
+
+    #[test]
+    fn capacity_zero_rejects_everything() {
+        use crate::{QueueExt, QueueFull};
+
+        let mut queue: Fixed<u8> = Fixed::new(0);
+        assert_eq!(queue.capacity(), 0);
+        assert!(queue.is_empty());
+        assert!(queue.is_full());
+        assert_eq!(queue.remaining(), 0);
+
+        assert_eq!(queue.enqueue(1), Some(1));
+        assert_eq!(queue.try_enqueue(1), Err(QueueFull(1)));
+        assert_eq!(queue.enqueue_overwrite(1), Some(1));
+        assert_eq!(queue.push_front(1), Some(1));
+        assert!(queue.expose_slots().is_none());
+        assert_eq!(queue.expose_all_slots().0.len(), 0);
+        unsafe {
+            queue.consider_enqueued(0);
+        }
+        assert_eq!(queue.bulk_enqueue(&[1, 2]), 0);
+        assert_eq!(queue.bulk_enqueue_remainder(&[1, 2]), &[1, 2]);
+        assert_eq!(queue.extend_counted([1, 2]), 0);
+
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.pop_back(), None);
+        assert_eq!(queue.peek(), None);
+        assert_eq!(queue.last(), None);
+        assert_eq!(queue.get(0), None);
+        assert!(queue.expose_items().is_none());
+        queue.consider_dequeued(0);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 2]), 0);
+        assert_eq!(queue.dequeue_all(), []);
+        assert_eq!(queue.drain().next(), None);
+        queue.retain(|_| true);
+        assert_eq!(queue.make_contiguous(), &[]);
+        assert_eq!(queue.iter().next(), None);
+
+        let mut other: Fixed<u8> = Fixed::new(2);
+        assert_eq!(other.enqueue(1), None);
+        assert_eq!(queue.bulk_enqueue_from(&mut other), 0);
+        assert_eq!(other.bulk_enqueue_from(&mut queue), 0);
+
+        assert_eq!(queue.clone(), Fixed::new(0));
+        queue.shrink_to_fit();
+        queue.grow(1);
+        assert_eq!(queue.enqueue(1), None);
+    }
+
+    #[test]
+    fn clear_removes_all_items() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert_eq!(queue.dequeue(), Some(1));
+
+        queue.clear();
+        assert!(queue.is_empty());
+        assert_eq!(queue.capacity(), 4);
+        assert_eq!(queue.bulk_enqueue(&[4, 5, 6, 7]), 4);
+
+        queue.reset();
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn reset_and_fill_reuses_the_allocation() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        let allocation = queue.data.as_ptr();
+
+        for round in 0..10u8 {
+            // Leave the queue partially filled, and wrapped around in some rounds.
+            let _ = queue.dequeue();
+            assert_eq!(queue.enqueue(round), None);
+
+            queue.reset_and_fill(&[round, round + 1, round + 2]);
+            assert_eq!(queue.capacity(), 4);
+            assert_eq!(queue.data.as_ptr(), allocation);
+            assert!(queue.iter().eq([round, round + 1, round + 2]));
+        }
+
+        queue.reset_and_fill(&[1, 2, 3, 4, 5, 6]);
+        assert_eq!(queue.capacity(), 6);
+        assert!(queue.iter().eq([1, 2, 3, 4, 5, 6]));
+    }
+
+    #[test]
+    fn indices_advance_modulo_capacity() {
+        let mut queue: Fixed<u8> = Fixed::new(3);
+        assert_eq!((queue.read_index(), queue.write_index()), (0, 0));
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2]), 2);
+        assert_eq!((queue.read_index(), queue.write_index()), (0, 2));
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.enqueue(3), None);
+        assert_eq!((queue.read_index(), queue.write_index()), (1, 0));
+
+        assert_eq!(queue.enqueue(4), None);
+        assert_eq!((queue.read_index(), queue.write_index()), (1, 1));
+
+        assert_eq!(queue.bulk_dequeue(&mut [0; 2]), 2);
+        assert_eq!((queue.read_index(), queue.write_index()), (0, 1));
+    }
+
+    #[test]
+    fn contains_checks_both_segments() {
+        let mut queue: Fixed<u8> = Fixed::new(3);
+        assert!(!queue.contains(&1));
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert!(queue.contains(&1));
+        assert!(queue.contains(&3));
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert!(!queue.contains(&1));
+
+        // This item wraps around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(4), None);
+        assert!(queue.contains(&2));
+        assert!(queue.contains(&4));
+        assert!(!queue.contains(&5));
+    }
+
+    #[test]
+    fn position_counts_from_the_front() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.position(|_| true), None);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[5, 6, 7]), 3);
+
+        assert_eq!(queue.position(|item| *item == 4), Some(0));
+        assert_eq!(queue.position(|item| *item == 6), Some(2));
+        assert_eq!(queue.position(|item| *item > 4), Some(1));
+        assert_eq!(queue.position(|item| *item == 1), None);
+    }
+
+    #[test]
+    fn bulk_enqueue_all_or_none_rejects_too_large_frames() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert_eq!(queue.dequeue(), Some(1));
+
+        assert!(!queue.bulk_enqueue_all_or_none(&[4, 5, 6]));
+        assert!(queue.iter().eq([2, 3]));
+
+        // Frames that fit are enqueued, even across the end of the ring-buffer.
+        assert!(queue.bulk_enqueue_all_or_none(&[4, 5]));
+        assert!(queue.iter().eq([2, 3, 4, 5]));
+        assert!(queue.bulk_enqueue_all_or_none(&[]));
+        assert!(!queue.bulk_enqueue_all_or_none(&[6]));
+    }
+
+    #[test]
+    fn bulk_enqueue_fills_both_segments() {
+        let mut queue: Fixed<u8> = Fixed::new(6);
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+
+        // The free slots span the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[5, 6, 7, 8, 9, 10, 11]), 5);
+        assert!(queue.iter().eq([4, 5, 6, 7, 8, 9]));
+        assert_eq!(queue.bulk_enqueue(&[12]), 0);
+    }
+
+    #[test]
+    fn bulk_dequeue_drains_both_segments() {
+        let mut queue: Fixed<u8> = Fixed::new(6);
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4, 5, 6]), 6);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 4]), 4);
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[7, 8, 9]), 3);
+
+        let mut buf = [0; 8];
+        assert_eq!(queue.bulk_dequeue(&mut buf), 5);
+        assert_eq!(buf[..5], [5, 6, 7, 8, 9]);
+        assert!(queue.is_empty());
+        assert_eq!(queue.bulk_dequeue(&mut buf), 0);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4, 5, 6]), 6);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 4]), 4);
+        assert_eq!(queue.bulk_enqueue(&[7, 8, 9]), 3);
+
+        let mut uninit_buf = [MaybeUninit::uninit(); 4];
+        assert_eq!(queue.bulk_dequeue_uninit(&mut uninit_buf), 4);
+        assert_eq!(unsafe { uninit_buf.assume_init_ref() }, &[5, 6, 7, 8]);
+        assert!(queue.iter().eq([9]));
+    }
+
+    #[test]
+    fn fills_free_slots_with_pattern() {
+        let mut queue: Fixed<u8> = Fixed::new(10);
+        assert_eq!(queue.fill_with_pattern(&[]), 0);
+
+        assert_eq!(queue.fill_with_pattern(&[1, 2, 3]), 10);
+        assert!(queue.iter().eq([1, 2, 3, 1, 2, 3, 1, 2, 3, 1]));
+        assert_eq!(queue.fill_with_pattern(&[1, 2, 3]), 0);
+
+        // The pattern continues across the end of the ring-buffer.
+        assert_eq!(queue.bulk_dequeue(&mut [0; 5]), 5);
+        assert_eq!(queue.enqueue(0), None);
+        assert_eq!(queue.fill_with_pattern(&[4, 5]), 4);
+        assert!(queue.iter().eq([3, 1, 2, 3, 1, 0, 4, 5, 4, 5]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn equal_queues_hash_equally() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let hasher = RandomState::new();
+        let mut queue: Fixed<u8> = Fixed::new(3);
+        let mut other: Fixed<u8> = Fixed::new(4);
+
+        assert_eq!(queue.bulk_enqueue(&[0, 1, 2]), 3);
+        assert_eq!(queue.dequeue(), Some(0));
+        // This item wraps around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(3), None);
+        assert_eq!(other.bulk_enqueue(&[1, 2, 3]), 3);
+
+        assert_eq!(queue, other);
+        assert_eq!(hasher.hash_one(&queue), hasher.hash_one(&other));
+
+        assert_eq!(other.dequeue(), Some(1));
+        assert_ne!(hasher.hash_one(&queue), hasher.hash_one(&other));
+    }
+
+    #[test]
+    fn enqueues_slots_with_closure() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+
+        let amount = queue.enqueue_slots_with(|writer| {
+            assert_eq!(writer.remaining(), 4);
+            assert_eq!(writer.bulk_push(&[1, 2]), 2);
+            assert_eq!(writer.push(3), None);
+        });
+        assert_eq!(amount, 3);
+        assert!(queue.iter().eq([1, 2, 3]));
+
+        // Only the kept items are enqueued.
+        assert_eq!(queue.dequeue(), Some(1));
+        let amount = queue.enqueue_slots_with(|writer| {
+            assert_eq!(writer.bulk_push(&[4, 5]), 1);
+            assert_eq!(writer.push(6), Some(6));
+            writer.commit_enqueued(0);
+        });
+        assert_eq!(amount, 0);
+        assert!(queue.iter().eq([2, 3]));
+
+        assert_eq!(queue.bulk_enqueue(&[4, 5]), 2);
+        assert_eq!(queue.enqueue_slots_with(|_| unreachable!()), 0);
+    }
+
+    #[test]
+    fn dequeues_items_with_closure() {
+        let mut queue: Fixed<u8> = Fixed::new(8);
+        assert_eq!(queue.dequeue_items_with(|_| unreachable!()), None::<()>);
+
+        // Consume length-prefixed frames until an incomplete one is found.
+        assert_eq!(queue.bulk_enqueue(&[2, 7, 8, 1, 9, 3, 1]), 7);
+        let parse = |items: &[u8]| match items.split_first() {
+            Some((&len, rest)) if rest.len() >= len as usize => (
+                1 + len as usize,
+                Some(rest[..len as usize].iter().sum::<u8>()),
+            ),
+            _ => (0, None),
+        };
+
+        assert_eq!(queue.dequeue_items_with(parse), Some(Some(15)));
+        assert!(queue.iter().eq([1, 9, 3, 1]));
+        assert_eq!(queue.dequeue_items_with(parse), Some(Some(9)));
+        assert_eq!(queue.dequeue_items_with(parse), Some(None));
+        assert!(queue.iter().eq([3, 1]));
+    }
+
+    #[test]
+    fn truncate_removes_items_from_the_back() {
+        let mut queue: Fixed<u8> = Fixed::new(5);
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4, 5]), 5);
+
+        queue.truncate(7);
+        assert_eq!(queue.len(), 5);
+
+        queue.truncate(2);
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.last(), Some(2));
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), None);
+
+        // The freed slots can be used again.
+        assert_eq!(queue.bulk_enqueue(&[6, 7, 8, 9, 10]), 5);
+        queue.truncate(0);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn discard_removes_items_from_the_front() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.discard(1), 0);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.discard(3), 3);
+        assert_eq!(queue.peek(), Some(4));
+
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[5, 6]), 2);
+        assert_eq!(queue.discard(10), 3);
+        assert!(queue.is_empty());
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.dequeue(), Some(7));
+    }
+
+    #[test]
+    fn bulk_dequeue_exact_requires_enough_items() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[5, 6]), 2);
+
+        let mut buf = [0; 4];
+        assert!(!queue.bulk_dequeue_exact(&mut buf));
+        assert_eq!(buf, [0; 4]);
+        assert!(queue.iter().eq([4, 5, 6]));
+
+        assert!(queue.bulk_dequeue_exact(&mut buf[..3]));
+        assert_eq!(buf, [4, 5, 6, 0]);
+        assert!(queue.is_empty());
+        assert!(queue.bulk_dequeue_exact(&mut []));
+    }
+
+    #[test]
+    fn is_send_and_sync() {
+        fn assert_send_sync<Q: Send + Sync>() {}
+
+        assert_send_sync::<Fixed<u8>>();
+    }
+
+    #[test]
+    fn rotates_like_vec_deque() {
+        use alloc::collections::VecDeque;
+
+        // Cover both a queue with free slots and a full queue.
+        for capacity in [5, 8] {
+            for n in 0..=5 {
+                let mut queue: Fixed<u8> = Fixed::new(capacity);
+                let mut control = VecDeque::new();
+
+                // Wrap the items around the end of the ring-buffer.
+                assert_eq!(queue.bulk_enqueue(&[0, 0, 0]), 3);
+                queue.discard(3);
+                for item in 1..=5 {
+                    assert_eq!(queue.enqueue(item), None);
+                    control.push_back(item);
+                }
+
+                queue.rotate_left(n);
+                control.rotate_left(n);
+                assert!(queue.iter().eq(control.iter().copied()));
+
+                queue.rotate_right(n);
+                control.rotate_right(n);
+                assert!(queue.iter().eq(control.iter().copied()));
+
+                queue.rotate_right(5 - n);
+                control.rotate_right(5 - n);
+                assert!(queue.iter().eq(control.iter().copied()));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot rotate a queue by more places than it has items")]
+    fn rotate_panics_beyond_length() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.enqueue(1), None);
+
+        queue.rotate_left(2);
+    }
+
+    #[test]
+    fn appends_items_from_other_queue() {
+        let mut queue: Fixed<u8> = Fixed::new(6);
+        let mut other: Fixed<u8> = Fixed::new(4);
+
+        assert_eq!(queue.bulk_enqueue(&[0, 0, 0, 1, 2, 3]), 6);
+        queue.discard(3);
+        // Both queues wrap around the end of their ring-buffers.
+        assert_eq!(other.bulk_enqueue(&[0, 0, 0, 4]), 4);
+        other.discard(3);
+        assert_eq!(other.bulk_enqueue(&[5, 6, 7]), 3);
+
+        // Whatever does not fit remains in the other queue.
+        assert_eq!(queue.append(&mut other), 3);
+        assert!(queue.iter().eq([1, 2, 3, 4, 5, 6]));
+        assert!(other.iter().eq([7]));
+
+        assert_eq!(queue.append(&mut other), 0);
+        queue.discard(6);
+        assert_eq!(queue.append(&mut other), 1);
+        assert!(queue.iter().eq([7]));
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn reports_free_and_contiguous_free_slots() {
+        let mut queue: Fixed<u8> = Fixed::new(6);
+        assert_eq!((queue.free(), queue.contiguous_free()), (6, 6));
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!((queue.free(), queue.contiguous_free()), (2, 2));
+
+        // The free slots wrap around the end of the ring-buffer.
+        queue.discard(3);
+        assert_eq!((queue.free(), queue.contiguous_free()), (5, 2));
+        assert_eq!(queue.expose_slots().map(|slots| slots.len()), Some(2));
+
+        // The items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[5, 6, 7]), 3);
+        assert_eq!((queue.free(), queue.contiguous_free()), (2, 2));
+
+        assert_eq!(queue.bulk_enqueue(&[8, 9]), 2);
+        assert_eq!((queue.free(), queue.contiguous_free()), (0, 0));
+    }
+
+    #[test]
+    fn enqueues_from_iter_without_losing_items() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        assert_eq!(queue.dequeue(), Some(b'f'));
+
+        // The free slots wrap around the end of the ring-buffer.
+        let mut iter = b"tofus".iter().copied();
+        assert_eq!(queue.enqueue_from_iter(&mut iter), 3);
+        assert!(queue.is_full());
+        assert!(iter.eq(*b"us"));
+
+        assert!(queue.iter().eq(*b"otof"));
+
+        // An exhausted iterator stops enqueueing before the queue is full.
+        assert_eq!(queue.dequeue(), Some(b'o'));
+        assert_eq!(queue.enqueue_from_iter(&mut core::iter::empty()), 0);
+        assert_eq!(queue.len(), 3);
+    }
+
+    #[test]
+    fn clamps_capacity_into_range() {
+        let queue: Fixed<u8> = Fixed::with_capacity_clamped(0, 4, 16);
+        assert_eq!(queue.capacity(), 4);
+
+        let queue: Fixed<u8> = Fixed::with_capacity_clamped(usize::MAX, 4, 16);
+        assert_eq!(queue.capacity(), 16);
+
+        let queue: Fixed<u8> = Fixed::with_capacity_clamped(9, 4, 16);
+        assert_eq!(queue.capacity(), 9);
+
+        let queue: Fixed<u8> = Fixed::with_capacity_clamped(0, 0, 16);
+        assert_eq!(queue.capacity(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_clamping_into_empty_range() {
+        let _: Fixed<u8> = Fixed::with_capacity_clamped(8, 16, 4);
+    }
+
+    #[test]
+    fn mutates_items_in_place_via_as_mut_slices() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert!(queue.as_mut_slices().0.is_empty());
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        assert_eq!(queue.bulk_enqueue(b"tof"), 2);
+
+        let (head, tail) = queue.as_mut_slices();
+        assert_eq!((&head[..], &tail[..]), (&b"fot"[..], &b"o"[..]));
+        head[0] = b'F';
+        tail[0] = b'O';
+
+        assert_eq!(queue.dequeue(), Some(b'F'));
+        assert_eq!(queue.dequeue(), Some(b'o'));
+        assert_eq!(queue.dequeue(), Some(b't'));
+        assert_eq!(queue.dequeue(), Some(b'O'));
+    }
+
+    #[test]
+    fn mutable_reference_is_a_queue() {
+        fn use_queue<Q: Queue<Item = u8>>(mut queue: Q) -> usize {
+            assert_eq!(queue.enqueue(1), None);
+            assert_eq!(queue.bulk_enqueue(&[2, 3, 4]), 3);
+            assert_eq!(queue.dequeue(), Some(1));
+
+            queue.len()
+        }
+
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(use_queue(&mut queue), 3);
+
+        // The queue was borrowed rather than moved, so it remains usable afterwards.
+        assert_eq!(queue.enqueue(5), None);
+        assert!(queue.iter().eq([2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn drives_boxed_trait_objects() {
+        let mut queues: Vec<Box<dyn Queue<Item = u8>>> = alloc::vec![
+            Box::new(Fixed::<u8>::new(3)),
+            Box::new(Static::<u8, 5>::new()),
+        ];
+
+        for queue in queues.iter_mut() {
+            assert_eq!(
+                queue.bulk_enqueue_remainder(b"ufotofu"),
+                &b"tofu"[queue.capacity() - 3..]
+            );
+            assert_eq!(queue.dequeue(), Some(b'u'));
+            assert_eq!(queue.enqueue_from_iter(&mut b"!".iter().copied()), 1);
+            assert!(queue.is_full());
+        }
+
+        let mut buf = [0; 3];
+        assert!(queues[0].bulk_dequeue_exact(&mut buf));
+        assert_eq!(&buf, b"fo!");
+
+        let mut buf = [0; 5];
+        assert!(queues[1].bulk_dequeue_exact(&mut buf));
+        assert_eq!(&buf, b"foto!");
+    }
+
+    #[test]
+    fn peeks_slices_across_the_end_of_the_ring_buffer() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.peek_slice(3), (&[][..], &[][..]));
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        assert_eq!(queue.dequeue(), Some(b'f'));
+        assert_eq!(queue.bulk_enqueue(b"tof"), 3);
+
+        let (head, tail) = queue.peek_slice(3);
+        assert_eq!((head, tail), (&b"ot"[..], &b"o"[..]));
+        assert_eq!([head, tail].concat(), b"oto");
+
+        // Peeking within the first segment, and beyond the length of the queue.
+        assert_eq!(queue.peek_slice(1), (&b"o"[..], &[][..]));
+        assert_eq!(queue.peek_slice(9), (&b"ot"[..], &b"of"[..]));
+        assert_eq!(queue.len(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn pipes_bytes_through_io_traits() {
+        use std::io::{self, Read, Write};
+
+        let data: Vec<u8> = (0..=255).collect();
+        let mut queue: Fixed<u8> = Fixed::new(7);
+        let mut output = Vec::new();
+
+        let mut written = 0;
+        let mut buf = [0; 5];
+        while written < data.len() {
+            written += queue.write(&data[written..]).unwrap();
+            assert_eq!(queue.write(&data[written..]).unwrap(), 0);
+            assert!(queue.is_full() || written == data.len());
+
+            let amount = queue.read(&mut buf).unwrap();
+            output.extend_from_slice(&buf[..amount]);
+        }
+
+        // Reading returns `Ok(0)` once the queue is empty, which ends the copy.
+        let remaining = queue.len() as u64;
+        assert_eq!(io::copy(&mut queue, &mut output).unwrap(), remaining);
+        assert_eq!(queue.read(&mut buf).unwrap(), 0);
+        assert_eq!(output, data);
+
+        queue.write_all(b"ufotofu").unwrap();
+        queue.flush().unwrap();
+        assert_eq!(
+            queue.write_all(b"!").unwrap_err().kind(),
+            io::ErrorKind::WriteZero
+        );
+    }
+
+    #[test]
+    fn bulk_enqueue_growing_preserves_all_items() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+
+        // Fits into the free slots, across the end of the ring-buffer.
+        queue.bulk_enqueue_growing(b"to");
+        assert_eq!(queue.capacity(), 4);
+
+        // Needs more than twice the capacity.
+        queue.bulk_enqueue_growing(b"fu, tofu");
+        assert_eq!(queue.capacity(), 12);
+
+        // Needs less than twice the capacity.
+        queue.bulk_enqueue_growing(b"!!");
+        assert_eq!(queue.capacity(), 24);
+
+        assert!(queue.iter().eq(*b"fotofu, tofu!!"));
+    }
+
+    #[test]
+    fn test_debug_with_alloc() {
+        let mut queue: Fixed<u8> = Fixed::new(2);
+        assert_eq!(queue.enqueue(7), None);
+
+        assert_eq!(
+            format!("{:?}", queue.debug_with_alloc()),
+            "Fixed { capacity: 2, len: 1, data: [7], alloc: Global }"
+        );
+
+        #[cfg(feature = "nightly")]
+        {
+            let queue: Fixed<u8, FailingAllocator> = Fixed::new_in(0, FailingAllocator);
+            assert_eq!(
+                format!("{:?}", queue.debug_with_alloc()),
+                "Fixed { capacity: 0, len: 0, data: [], alloc: FailingAllocator }"
+            );
+        }
+    }
+
+    #[test]
+    fn bulk_dequeues_exactly_into_uninit_buffer_or_not_at_all() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(b"to"), 2);
+
+        // Not enough items: neither the queue nor the buffer change.
+        let mut buf = [MaybeUninit::new(0); 5];
+        assert!(!queue.bulk_dequeue_exact_uninit(&mut buf));
+        assert_eq!(queue.len(), 4);
+        assert!(queue.iter().eq(*b"foto"));
+        assert_eq!(unsafe { buf.assume_init_ref() }, &[0; 5]);
+
+        let mut buf = [MaybeUninit::uninit(); 4];
+        assert!(queue.bulk_dequeue_exact_uninit(&mut buf));
+        assert_eq!(unsafe { buf.assume_init_ref() }, b"foto");
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn fills_from_reader_one_segment_at_a_time() {
+        let mut reader: &[u8] = b"tofu and more";
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        assert_eq!(queue.dequeue(), Some(b'f'));
+
+        // The free slots wrap around the end of the ring-buffer, so the first read only fills
+        // the slot at its end.
+        assert_eq!(queue.fill_from_read(&mut reader).unwrap(), 1);
+        assert_eq!(queue.fill_from_read(&mut reader).unwrap(), 2);
+        assert_eq!(queue.fill_from_read(&mut reader).unwrap(), 0);
+        assert!(queue.iter().eq(*b"otof"));
+        assert_eq!(reader, b"u and more");
+
+        // An exhausted reader enqueues nothing.
+        let mut reader: &[u8] = b"";
+        assert_eq!(queue.bulk_dequeue(&mut [0; 4]), 4);
+        assert_eq!(queue.fill_from_read(&mut reader).unwrap(), 0);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn drains_to_writer_respecting_short_writes() {
+        struct ShortWriter(Vec<u8>);
+
+        impl std::io::Write for ShortWriter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                let amount = min(buf.len(), 2);
+                self.0.extend_from_slice(&buf[..amount]);
+
+                Ok(amount)
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(b"to"), 2);
+
+        let mut writer = Vec::new();
+        assert_eq!(queue.drain_to_write(&mut writer).unwrap(), 3);
+        assert_eq!(writer, b"fot");
+        assert!(queue.iter().eq(*b"o"));
+
+        assert_eq!(queue.bulk_enqueue(b"fu!"), 3);
+        let mut writer = ShortWriter(Vec::new());
+        assert_eq!(queue.drain_to_write(&mut writer).unwrap(), 2);
+        assert!(queue.iter().eq(*b"u!"));
+        assert_eq!(queue.drain_to_write(&mut writer).unwrap(), 2);
+        assert_eq!(queue.drain_to_write(&mut writer).unwrap(), 0);
+        assert_eq!(writer.0, b"ofu!");
+    }
+
+    #[test]
+    fn debug_validate_accepts_consistent_state() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        queue.debug_validate();
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        assert_eq!(queue.bulk_enqueue(b"to"), 2);
+        queue.debug_validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "read index out of bounds")]
+    fn debug_validate_panics_on_corrupted_read_index() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+
+        queue.read = 4;
+        queue.debug_validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "queue contains more items than its capacity")]
+    fn debug_validate_panics_on_corrupted_amount() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+
+        queue.amount = 5;
+        queue.debug_validate();
+    }
+
+    #[test]
+    fn copies_items_to_slice_without_dequeueing() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(b"to"), 2);
+
+        let mut out = [0; 6];
+        assert_eq!(queue.copy_to_slice(&mut out), 4);
+        assert_eq!(&out[..4], b"foto");
+        assert_eq!(queue.len(), 4);
+
+        let mut out = [0; 3];
+        assert_eq!(queue.copy_to_slice(&mut out), 3);
+        assert_eq!(&out, b"fot");
+        assert!(queue.iter().eq(*b"foto"));
+    }
+
+    #[test]
+    fn clone_from_reuses_allocation_of_equal_capacity() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        let mut dst: Fixed<u8> = Fixed::new(4);
+        assert_eq!(dst.bulk_enqueue(b"!!"), 2);
+        let allocation = dst.data.as_ptr();
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        for round in 0..4 {
+            // Every round moves the items further around the ring-buffer.
+            assert!(queue.dequeue().is_some());
+            assert_eq!(queue.enqueue(round), None);
+
+            dst.clone_from(&queue);
+            assert_eq!(dst.capacity(), 4);
+            assert_eq!(dst.data.as_ptr(), allocation);
+            assert!(dst.iter().eq(queue.iter()));
+        }
+    }
+
+    #[test]
+    fn clone_from_queue_of_different_capacity_works_like_clone() {
+        let mut queue: Fixed<u8> = Fixed::new_zeroizing(4);
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+
+        let mut dst: Fixed<u8> = Fixed::new(2);
+        dst.clone_from(&queue);
+        assert_eq!(dst.capacity(), 4);
+        assert!(dst.zeroizing);
+        assert_eq!(dst, queue);
+    }
+
+    #[test]
+    fn mutates_front_item_in_place() {
+        let mut queue: Fixed<u8> = Fixed::new(3);
+        assert_eq!(queue.front_mut(), None);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        *queue.front_mut().unwrap() += 10;
+        assert_eq!(queue.dequeue(), Some(11));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+
+        // These items wrap around to the start of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[4, 5]), 2);
+        assert_eq!(queue.dequeue(), Some(4));
+        *queue.front_mut().unwrap() = 50;
+        assert_eq!(queue.peek(), Some(50));
+        assert_eq!(queue.dequeue(), Some(50));
+    }
+
+    #[test]
+    fn enqueue_evicting_matches_default_implementation() {
+        let mut queue: Fixed<u8> = Fixed::new(3);
+        let mut memory = [MaybeUninit::uninit(); 3];
+        // Uses the default implementation of `enqueue_evicting`.
+        let mut other = crate::Borrowed::new(&mut memory);
+
+        for item in 0..8 {
+            assert_eq!(queue.enqueue_evicting(item), other.enqueue_evicting(item));
+            assert_eq!(queue.len(), other.len());
+            assert_eq!(queue.peek(), other.peek());
+        }
+        assert_eq!(queue.enqueue_evicting(8), Some(5));
+        assert_eq!(other.enqueue_evicting(8), Some(5));
+
+        // Both return the item itself at capacity zero.
+        let mut queue: Fixed<u8> = Fixed::new(0);
+        let mut other = crate::Borrowed::new(&mut []);
+        assert_eq!(queue.enqueue_evicting(1), Some(1));
+        assert_eq!(other.enqueue_evicting(1), Some(1));
+    }
+
+    #[test]
+    fn bulk_enqueues_vectored_from_several_buffers() {
+        let mut queue: Fixed<u8> = Fixed::new(6);
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+
+        // The queue fills up partway through the second buffer, which wraps around the end of
+        // the ring-buffer.
+        assert_eq!(queue.bulk_enqueue_vectored(&[b"to", b"fuqu", b"eues"]), 6);
+        assert!(queue.iter().eq(*b"tofuqu"));
+        assert_eq!(queue.bulk_enqueue_vectored(&[b"!"]), 0);
+    }
+
+    #[test]
+    fn bulk_dequeues_vectored_into_several_buffers() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(b"to"), 2);
+
+        let mut first = [0; 3];
+        let mut second = [0; 2];
+        assert_eq!(
+            queue.bulk_dequeue_vectored(&mut [&mut first, &mut second]),
+            4
+        );
+        assert_eq!(&first, b"fot");
+        assert_eq!(&second, b"o\0");
+        assert!(queue.is_empty());
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        let mut first = [0; 1];
+        let mut second = [0; 1];
+        assert_eq!(
+            queue.bulk_dequeue_vectored(&mut [&mut [], &mut first, &mut second]),
+            2
+        );
+        assert_eq!((&first, &second), (b"u", b"f"));
+        assert_eq!(queue.dequeue(), Some(b'o'));
+    }
+
+    #[test]
+    fn is_contiguous_matches_exposed_items() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+        assert!(queue.is_contiguous());
+
+        assert_eq!(queue.bulk_enqueue(b"ufot"), 4);
+        assert!(queue.is_contiguous());
+        assert_eq!(queue.expose_items().map(|items| items.len()), Some(4));
+
+        assert_eq!(queue.bulk_dequeue(&mut [0; 2]), 2);
+        assert!(queue.is_contiguous());
+        assert_eq!(queue.expose_items().map(|items| items.len()), Some(2));
+
+        // This item wraps around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(b'o'), None);
+        assert!(!queue.is_contiguous());
+        assert_eq!(queue.expose_items().map(|items| items.len()), Some(2));
+
+        assert_eq!(queue.make_contiguous(), b"oto");
+        assert!(queue.is_contiguous());
+        assert_eq!(queue.expose_items().map(|items| items.len()), Some(3));
+    }
+
+    #[test]
+    fn round_trips_vec_through_queue() {
+        let mut queue = Fixed::from(alloc::vec![1, 2, 3]);
+        assert_eq!(queue.capacity(), 3);
+        assert!(queue.is_full());
+        assert!(queue.iter().eq([1, 2, 3]));
+
+        assert_eq!(queue.dequeue(), Some(1));
+        // This item wraps around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(4), None);
+        assert_eq!(Vec::from(queue), alloc::vec![2, 3, 4]);
+
+        assert_eq!(Vec::from(Fixed::from(Vec::<u8>::new())), Vec::<u8>::new());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn round_trips_wrapped_vec_deque_through_queue() {
+        use std::collections::VecDeque;
+
+        let mut deque = VecDeque::new();
+        deque.push_back(2);
+        deque.push_back(3);
+        // This item wraps around to the end of the deque's buffer.
+        deque.push_front(1);
+        assert!(!deque.as_slices().1.is_empty());
+
+        let mut queue = Fixed::from(deque);
+        assert_eq!(queue.capacity(), 3);
+        assert!(queue.iter().eq([1, 2, 3]));
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.enqueue(4), None);
+        assert!(VecDeque::from(queue).into_iter().eq([2, 3, 4]));
+    }
+
+    #[test]
+    fn dequeues_while_predicate_holds() {
+        let mut queue: Fixed<u8> = Fixed::new(6);
+        assert_eq!(queue.bulk_enqueue(&[1, 1, 1, 1]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 4]), 4);
+        // The run of even numbers wraps around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[2, 4, 6, 7, 8]), 5);
+
+        assert_eq!(queue.dequeue_while(|item| item % 2 == 0), 3);
+        assert_eq!(queue.peek(), Some(7));
+        assert_eq!(queue.dequeue_while(|item| item % 2 == 0), 0);
+        assert_eq!(queue.len(), 2);
+
+        assert_eq!(queue.dequeue_while(|_| true), 2);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn peeks_while_predicate_holds_across_the_end_of_the_ring_buffer() {
+        let mut queue: Fixed<u8> = Fixed::new(6);
+        assert_eq!(queue.bulk_enqueue(&[1, 1, 1, 1]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 4]), 4);
+        // The run of even numbers spans both segments of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[2, 4, 6, 7, 8]), 5);
+
+        assert_eq!(queue.peek_while(|item| item % 2 == 0), 3);
+        assert_eq!(queue.peek_while(|_| true), 5);
+        assert_eq!(queue.peek_while(|item| *item > 2), 0);
+        assert_eq!(queue.len(), 5);
+
+        assert_eq!(queue.dequeue_while(|item| item % 2 == 0), 3);
+        assert_eq!(queue.peek_while(|item| item % 2 == 0), 0);
+    }
+
+    #[test]
+    fn test_debug_impl() {
+        let mut queue: Fixed<u8> = Fixed::new(4);
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.enqueue(21), None);
+        assert_eq!(queue.enqueue(196), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Fixed { capacity: 4, len: 3, data: [7, 21, 196] }"
+        );
+
+        assert_eq!(queue.dequeue(), Some(7));
+        assert_eq!(
+            format!("{:?}", queue),
+            "Fixed { capacity: 4, len: 2, data: [21, 196] }"
+        );
+
+        assert_eq!(queue.dequeue(), Some(21));
+        assert_eq!(
+            format!("{:?}", queue),
+            "Fixed { capacity: 4, len: 1, data: [196] }"
+        );
+
+        assert_eq!(queue.enqueue(33), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Fixed { capacity: 4, len: 2, data: [196, 33] }"
+        );
+
+        assert_eq!(queue.enqueue(17), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Fixed { capacity: 4, len: 3, data: [196, 33, 17] }"
+        );
+
+        assert_eq!(queue.enqueue(200), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Fixed { capacity: 4, len: 4, data: [196, 33, 17, 200] }"
+        );
+    }
+
+    #[test]
+    fn test_debug_impl_at_boundaries() {
+        let mut queue: Fixed<u8> = Fixed::new_in(4, Global);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Fixed { capacity: 4, len: 0, data: [] }"
+        );
+
+        // A single item in the last slot of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Fixed { capacity: 4, len: 1, data: [4] }"
+        );
+
+        // A full queue that wraps around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[5, 6, 7]), 3);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Fixed { capacity: 4, len: 4, data: [4, 5, 6, 7] }"
+        );
+
+        // A single item in the first slot, after the read index wrapped around.
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Fixed { capacity: 4, len: 1, data: [7] }"
+        );
+
+        // An empty queue whose read index is not zero.
+        assert_eq!(queue.dequeue(), Some(7));
+        assert_eq!(
+            format!("{:?}", queue),
+            "Fixed { capacity: 4, len: 0, data: [] }"
+        );
+
+        let queue: Fixed<u8> = Fixed::new(0);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Fixed { capacity: 0, len: 0, data: [] }"
         );
     }
 }
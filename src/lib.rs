@@ -1,40 +1,110 @@
 #![no_std]
-#![feature(allocator_api)]
-#![feature(maybe_uninit_slice)]
-#![feature(maybe_uninit_uninit_array)]
-#![feature(maybe_uninit_write_slice)]
-#![feature(new_uninit)]
-#![feature(debug_closure_helpers)]
+#![cfg_attr(
+    all(feature = "nightly", any(feature = "std", feature = "alloc")),
+    feature(allocator_api, dropck_eyepatch)
+)]
 
 //! A [trait](Queue) and implementations of non-blocking, infallible [FIFO queues](https://en.wikipedia.org/wiki/Queue_(abstract_data_type)) that support bulk enqueueing and bulk dequeueing via APIs inspired by [ufotofu](https://crates.io/crates/ufotofu).
 //!
 //! ## Queue Implementations
 //!
-//! So far, there are two implementations:
+//! So far, there are four implementations:
 //!
 //! - [`Fixed`], which is a heap-allocated ring-buffer of unchanging capacity. It is gated behind the `std` or `alloc` feature, the prior of which is enabled by default.
 //! - [`Static`], which works exactly like [`Fixed`], but is backed by an array of static capacity. It requires no allocations.
+//! - [`Borrowed`], which works like [`Static`], but is backed by a borrowed slice of memory whose length need only be known at runtime. It requires no allocations.
+//! - [`Elastic`], which is a heap-allocated ring-buffer that grows and shrinks its capacity within certain parameters, to free up memory under low load. It is gated behind the `std` or `alloc` feature.
 //!
-//! Future plans include an elastic queue that grows and shrinks its capacity within certain parameters, to free up memory under low load.
+//! [`AnyQueue`] picks between a [`Static`] and a [`Fixed`] queue depending on the capacity requested at runtime, so that small queues require no allocation. It is gated behind the `std` or `alloc` feature.
+//!
+//! For passing items between two threads without locking, the [`spsc`] module provides a single-producer single-consumer queue. It is gated behind the `std` or `alloc` feature.
+//!
+//! For async code, `AsyncQueue` wraps a [`Fixed`] queue so that enqueueing waits for a free slot and dequeueing waits for an item. It is gated behind the `async` feature.
+//!
+//! The [`Tee`] adaptor wraps any queue, and hands every item dequeued from it to a closure. The [`Metered`] adaptor wraps any queue, and records its peak length and the total numbers of items enqueued and dequeued. The [`Batched`] adaptor wraps any queue, and holds back its items until there are enough of them to be dequeued as one batch. The [`Map`] adaptor wraps any queue, and converts its items to and from a different item type.
+//!
+//! All of these require their items to be [`Copy`]. For items that are not, there is [`OwningFixed`], which works like [`Fixed`] but does not implement [`Queue`]: it only supports enqueueing and dequeueing one item at a time, and it drops any items it still contains when it is dropped itself. It is gated behind the `std` or `alloc` feature.
+//!
+//! ## Toolchain
+//!
+//! The `nightly` feature, which is enabled by default, requires a nightly toolchain. With it, [`Fixed`] and [`OwningFixed`] are generic over their [memory allocator](https://doc.rust-lang.org/alloc/alloc/trait.Allocator.html) (via the `allocator_api` feature), and the items of a [`Fixed`] queue may borrow values that are dropped before the queue (via the `dropck_eyepatch` feature).
+//!
+//! Without the `nightly` feature, the crate builds on a stable toolchain. [`Fixed`] and [`OwningFixed`] then always use the global allocator: their allocator parameter still defaults to `Global`, but the crate exports its own sealed `Allocator` trait and `Global` allocator in place of the unstable ones. The items of a [`Fixed`] queue must then outlive the queue.
 
 #[cfg(feature = "std")]
 extern crate std;
 
-#[cfg(feature = "alloc")]
+#[cfg(any(feature = "std", feature = "alloc"))]
 extern crate alloc;
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod allocator;
+#[cfg(all(not(feature = "nightly"), any(feature = "std", feature = "alloc")))]
+pub use allocator::{Allocator, Global};
+
 #[cfg(any(feature = "std", feature = "alloc"))]
 mod fixed;
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub use fixed::Fixed;
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod elastic;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use elastic::{Elastic, ShrinkPolicy};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod owning_fixed;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use owning_fixed::OwningFixed;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod any_queue;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use any_queue::AnyQueue;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod spsc;
+
+#[cfg(feature = "async")]
+mod async_;
+#[cfg(feature = "async")]
+pub use async_::AsyncQueue;
+
 mod static_;
 pub use static_::Static;
 
+mod borrowed;
+pub use borrowed::Borrowed;
+
+mod batched;
+pub use batched::Batched;
+
+mod enqueue_guard;
+pub use enqueue_guard::EnqueueGuard;
+
+mod map;
+pub use map::Map;
+
+mod slot_writer;
+pub use slot_writer::SlotWriter;
+
+mod metered;
+pub use metered::Metered;
+
+mod tee;
+pub use tee::Tee;
+
 use core::cmp::min;
+use core::fmt;
 use core::mem::MaybeUninit;
 
 /// A first-in-first-out queue. Provides methods for bulk transfer of items similar to [ufotofu](https://crates.io/crates/ufotofu) [`BulkProducer`](https://docs.rs/ufotofu/0.1.0/ufotofu/sync/trait.BulkProducer.html)s and [`BulkConsumer`](https://docs.rs/ufotofu/0.1.0/ufotofu/sync/trait.BulkConsumer.html)s.
+///
+/// The trait is dyn-compatible, so queues of different types can be used through a
+/// `dyn Queue<Item = T>`. The few methods that are generic over closures, iterators, or other
+/// queues require `Self: Sized`, so they cannot be called on a `dyn Queue` directly, but they are
+/// available on `Box<dyn Queue<Item = T>>` and `&mut dyn Queue<Item = T>`, which implement the
+/// trait themselves.
 pub trait Queue {
     /// The type of items to manage in the queue.
     type Item: Copy;
@@ -47,11 +117,102 @@ pub trait Queue {
         self.len() == 0
     }
 
+    /// Return the maximum number of items the queue can hold at the same time.
+    fn capacity(&self) -> usize;
+
+    /// Return how many more items can be enqueued before the queue is full. Equal to `self.capacity() - self.len()`.
+    fn remaining(&self) -> usize {
+        self.capacity() - self.len()
+    }
+
+    /// Return whether the queue is full. Must return `true` if and only if `self.len()` equals `self.capacity()`.
+    fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Return the fraction of the capacity that is in use, between `0.0` for an empty queue and
+    /// `1.0` for a full one. A queue of capacity zero has an occupancy of `0.0`.
+    fn occupancy(&self) -> f32 {
+        match self.capacity() {
+            0 => 0.0,
+            capacity => self.len() as f32 / capacity as f32,
+        }
+    }
+
     /// Attempt to enqueue an item.
     ///
     /// Will return the item instead of enqueueing it if the queue is full at the time of calling.
+    /// Ignoring the returned value is a compile-time warning, since it might silently drop an item:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// use ufotofu_queues::{Queue, Static};
+    ///
+    /// let mut queue: Static<u8, 1> = Static::new();
+    /// queue.enqueue(7);
+    /// ```
+    #[must_use = "the item is returned instead of being enqueued if the queue is full"]
     fn enqueue(&mut self, item: Self::Item) -> Option<Self::Item>;
 
+    /// Attempt to enqueue an item, reporting a full queue as an error.
+    ///
+    /// Will return a [`QueueFull`] error carrying the item instead of enqueueing it if the queue
+    /// is full at the time of calling.
+    fn try_enqueue(&mut self, item: Self::Item) -> Result<(), QueueFull<Self::Item>> {
+        match self.enqueue(item) {
+            None => Ok(()),
+            Some(item) => Err(QueueFull(item)),
+        }
+    }
+
+    /// Enqueue an item, even if the queue is full. If it is, the oldest item is dequeued to make
+    /// room and returned. Otherwise, this behaves exactly like [`Queue::enqueue`] and returns
+    /// `None`.
+    ///
+    /// In a queue of capacity zero, the item itself is returned.
+    ///
+    /// #### Implementation Notes
+    ///
+    /// The default implementation calls `enqueue`, and if that fails, `dequeue` followed by
+    /// `enqueue`. Ring-buffers can overwrite the oldest item in place instead.
+    fn enqueue_evicting(&mut self, item: Self::Item) -> Option<Self::Item> {
+        let item = self.enqueue(item)?;
+
+        match self.dequeue() {
+            None => Some(item),
+            Some(evicted) => {
+                let rejected = self.enqueue(item);
+                debug_assert!(rejected.is_none(), "queue rejected item despite free slots");
+
+                Some(evicted)
+            }
+        }
+    }
+
+    /// Attempt to enqueue an item if a predicate on the current state of the queue holds, for
+    /// example `|queue| queue.len() < threshold`. The predicate is called before enqueueing,
+    /// with no other access to the queue in between.
+    ///
+    /// Will return the item instead of enqueueing it if the predicate does not hold, or if the
+    /// queue is full at the time of calling.
+    fn enqueue_if<F: FnOnce(&Self) -> bool>(
+        &mut self,
+        item: Self::Item,
+        pred: F,
+    ) -> Result<(), Self::Item>
+    where
+        Self: Sized,
+    {
+        if pred(self) {
+            match self.enqueue(item) {
+                None => Ok(()),
+                Some(item) => Err(item),
+            }
+        } else {
+            Err(item)
+        }
+    }
+
     /// A low-level method for enqueueing multiple items at a time. If you are only *working* with
     /// queues (rather than implementing them yourself), you will probably want to ignore this method
     /// and use [Queue::bulk_enqueue] instead.
@@ -60,6 +221,7 @@ pub trait Queue {
     /// be enqueued. To be used together with [Queue::consider_enqueued].
     ///
     /// Will return `None` if the queue is full at the time of calling.
+    #[must_use]
     fn expose_slots(&mut self) -> Option<&mut [MaybeUninit<Self::Item>]>;
 
     /// A low-level method for enqueueing multiple items at a time. If you are only *working* with
@@ -91,6 +253,33 @@ pub trait Queue {
     /// been set to [`MaybeUninit::uninit`].
     unsafe fn consider_enqueued(&mut self, amount: usize);
 
+    /// Expose a non-empty slice of free slots to a closure, which fills them with items that
+    /// should be enqueued through a [`SlotWriter`]. The items the writer has written (or kept, see
+    /// [`SlotWriter::commit_enqueued`]) are then enqueued, and their number is returned. Unlike
+    /// pairing [Queue::expose_slots] with [Queue::consider_enqueued], this is safe: the writer
+    /// tracks which slots are initialised, so the queue never enqueues uninitialised memory.
+    ///
+    /// Will not call the closure and return `0` if the queue is full at the time of calling.
+    fn enqueue_slots_with<F: FnOnce(&mut SlotWriter<'_, Self::Item>)>(&mut self, f: F) -> usize
+    where
+        Self: Sized,
+    {
+        match self.expose_slots() {
+            None => 0,
+            Some(slots) => {
+                let mut writer = SlotWriter::new(slots);
+                f(&mut writer);
+                let amount = writer.written();
+
+                unsafe {
+                    self.consider_enqueued(amount);
+                }
+
+                amount
+            }
+        }
+    }
+
     /// Enqueue a non-zero number of items by reading them from a given buffer and returning how
     /// many items were enqueued.
     ///
@@ -101,12 +290,13 @@ pub trait Queue {
     /// The default implementation orchestrates `expose_slots` and `consider_queued` in a
     /// straightforward manner. Only provide your own implementation if you can do better
     /// than that.
+    #[must_use = "fewer items than the buffer holds might have been enqueued"]
     fn bulk_enqueue(&mut self, buffer: &[Self::Item]) -> usize {
         match self.expose_slots() {
             None => 0,
             Some(slots) => {
                 let amount = min(slots.len(), buffer.len());
-                MaybeUninit::copy_from_slice(&mut slots[..amount], &buffer[..amount]);
+                slots[..amount].write_copy_of_slice(&buffer[..amount]);
                 unsafe {
                     self.consider_enqueued(amount);
                 }
@@ -116,11 +306,218 @@ pub trait Queue {
         }
     }
 
+    /// Enqueue as many items from the front of a given buffer as fit into the queue, and return
+    /// the remaining items that were not enqueued.
+    ///
+    /// Will return an empty slice if all items were enqueued, and the whole buffer if the queue
+    /// is full at the time of calling.
+    ///
+    /// #### Implementation Notes
+    ///
+    /// The default implementation calls `bulk_enqueue` until either the buffer is exhausted or
+    /// the queue is full.
+    fn bulk_enqueue_remainder<'a>(&mut self, buffer: &'a [Self::Item]) -> &'a [Self::Item] {
+        let mut remainder = buffer;
+
+        while !remainder.is_empty() {
+            let amount = self.bulk_enqueue(remainder);
+            if amount == 0 {
+                break;
+            }
+            remainder = &remainder[amount..];
+        }
+
+        remainder
+    }
+
+    /// Enqueue either all items of a given buffer or none of them, and return whether the items
+    /// were enqueued. Useful for enqueueing frames that must not be split up.
+    ///
+    /// Will return `false` and leave the queue unchanged if fewer than `buffer.len()` slots are
+    /// free at the time of calling.
+    ///
+    /// #### Implementation Notes
+    ///
+    /// The default implementation checks `remaining` and then calls `bulk_enqueue` until the
+    /// buffer is exhausted.
+    #[must_use = "the items are not enqueued at all if they do not all fit"]
+    fn bulk_enqueue_all_or_none(&mut self, buffer: &[Self::Item]) -> bool {
+        if self.remaining() < buffer.len() {
+            false
+        } else {
+            let remainder = self.bulk_enqueue_remainder(buffer);
+            debug_assert!(
+                remainder.is_empty(),
+                "queue rejected items despite free slots"
+            );
+
+            true
+        }
+    }
+
+    /// Reserve `n` free slots, and return a guard through which up to `n` items can be enqueued
+    /// without having to handle a full queue.
+    ///
+    /// Will return `None` if fewer than `n` slots are free at the time of calling.
+    fn reserve(&mut self, n: usize) -> Option<EnqueueGuard<'_, Self>>
+    where
+        Self: Sized,
+    {
+        EnqueueGuard::new(self, n)
+    }
+
+    /// Enqueue all items of a given buffer, for code that has already made sure they fit.
+    ///
+    /// Panic and leave the queue unchanged if fewer than `buffer.len()` slots are free at the
+    /// time of calling. The panic message states how many slots were missing.
+    fn enqueue_all(&mut self, buffer: &[Self::Item]) {
+        if !self.bulk_enqueue_all_or_none(buffer) {
+            let remaining = self.remaining();
+            panic!(
+                "cannot enqueue {} items with only {} free slots, {} short",
+                buffer.len(),
+                remaining,
+                buffer.len() - remaining
+            );
+        }
+    }
+
+    /// Enqueue the items of several buffers in order, until either all buffers are exhausted or
+    /// the queue is full, and return the total number of items that were enqueued. Useful for
+    /// gather writes, such as a header followed by a body. If the queue fills up partway through
+    /// a buffer, the items before that point are enqueued and the rest are not.
+    ///
+    /// #### Implementation Notes
+    ///
+    /// The default implementation calls `bulk_enqueue_remainder` on each buffer in turn.
+    fn bulk_enqueue_vectored(&mut self, bufs: &[&[Self::Item]]) -> usize {
+        let mut count = 0;
+
+        for buf in bufs {
+            let remainder = self.bulk_enqueue_remainder(buf);
+            count += buf.len() - remainder.len();
+
+            if !remainder.is_empty() {
+                break;
+            }
+        }
+
+        count
+    }
+
+    /// Fill all free slots of the queue by repeating the items of a given pattern, and return
+    /// how many items were enqueued.
+    ///
+    /// Will return `0` if the pattern is empty, or if the queue is full at the time of calling.
+    fn fill_with_pattern(&mut self, pattern: &[Self::Item]) -> usize {
+        let mut count = 0;
+
+        if !pattern.is_empty() {
+            while let Some(slots) = self.expose_slots() {
+                let amount = slots.len();
+                for (i, slot) in slots.iter_mut().enumerate() {
+                    slot.write(pattern[(count + i) % pattern.len()]);
+                }
+
+                unsafe {
+                    self.consider_enqueued(amount);
+                }
+                count += amount;
+            }
+        }
+
+        count
+    }
+
+    /// Enqueue a non-zero number of items by moving them from the front of another queue and
+    /// returning how many items were moved. The items are copied directly between the memory of
+    /// both queues, without an intermediate buffer.
+    ///
+    /// Will return `0` if this queue is full or the other queue is empty at the time of calling.
+    ///
+    /// #### Implementation Notes
+    ///
+    /// The default implementation orchestrates `expose_items` and `consider_dequeued` of the
+    /// other queue with `expose_slots` and `consider_enqueued` of this queue in a straightforward
+    /// manner. Only provide your own implementation if you can do better than that.
+    fn bulk_enqueue_from<Q: Queue<Item = Self::Item>>(&mut self, other: &mut Q) -> usize
+    where
+        Self: Sized,
+    {
+        let amount = match other.expose_items() {
+            None => 0,
+            Some(items) => match self.expose_slots() {
+                None => 0,
+                Some(slots) => {
+                    let amount = min(slots.len(), items.len());
+                    slots[..amount].write_copy_of_slice(&items[..amount]);
+
+                    amount
+                }
+            },
+        };
+
+        unsafe {
+            self.consider_enqueued(amount);
+        }
+        other.consider_dequeued(amount);
+
+        amount
+    }
+
+    /// Enqueue items pulled from an iterator until either the queue is full or the iterator is
+    /// exhausted, and return how many items were enqueued.
+    ///
+    /// An item is only pulled from the iterator once there is a free slot for it, so no items are
+    /// lost when the queue fills up: all items that were not enqueued remain in the iterator.
+    fn enqueue_from_iter<I: Iterator<Item = Self::Item>>(&mut self, iter: &mut I) -> usize
+    where
+        Self: Sized,
+    {
+        let mut count = 0;
+
+        while let Some(slots) = self.expose_slots() {
+            let mut amount = 0;
+            for slot in slots.iter_mut() {
+                match iter.next() {
+                    Some(item) => {
+                        slot.write(item);
+                        amount += 1;
+                    }
+                    None => break,
+                }
+            }
+
+            let exhausted = amount < slots.len();
+            unsafe {
+                self.consider_enqueued(amount);
+            }
+            count += amount;
+
+            if exhausted {
+                break;
+            }
+        }
+
+        count
+    }
+
     /// Attempt to dequeue the next item.
     ///
     /// Will return `None` if the queue is empty at the time of calling.
+    #[must_use = "use `consider_dequeued` to discard items without reading them"]
     fn dequeue(&mut self) -> Option<Self::Item>;
 
+    /// Return the next item that would be dequeued, without removing it from the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn peek(&self) -> Option<Self::Item>;
+
+    /// Return the most recently enqueued item, without removing it from the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn last(&self) -> Option<Self::Item>;
+
     /// A low-level method for dequeueing multiple items at a time. If you are only *working* with
     /// queues (rather than implementing them yourself), you will probably want to ignore this method
     /// and use [Queue::bulk_dequeue] or [Queue::bulk_dequeue_uninit] instead.
@@ -130,6 +527,7 @@ pub trait Queue {
     /// To be used together with [Queue::consider_dequeued].
     ///
     /// Will return `None` if the queue is empty at the time of calling.
+    #[must_use]
     fn expose_items(&mut self) -> Option<&[Self::Item]>;
 
     /// A low-level method for dequeueing multiple items at a time. If you are only *working* with
@@ -144,6 +542,61 @@ pub trait Queue {
     /// Callers must not mark items as dequeued that had not previously been exposed by `expose_items`.
     fn consider_dequeued(&mut self, amount: usize);
 
+    /// Expose a non-empty slice of items to a closure, which returns how many of the first items
+    /// it has consumed together with a result value. Those items are then dequeued, and the
+    /// result value is returned. This pairs [Queue::expose_items] with [Queue::consider_dequeued]
+    /// so that client code cannot forget either step.
+    ///
+    /// Will not call the closure and return `None` if the queue is empty at the time of calling.
+    /// Consuming more than the number of exposed items is a bug, and panics in debug builds.
+    fn dequeue_items_with<R, F: FnOnce(&[Self::Item]) -> (usize, R)>(&mut self, f: F) -> Option<R>
+    where
+        Self: Sized,
+    {
+        let (amount, result) = {
+            let items = self.expose_items()?;
+            let (amount, result) = f(items);
+            debug_assert!(
+                amount <= items.len(),
+                "cannot consider more items dequeued than were exposed"
+            );
+
+            (amount, result)
+        };
+
+        self.consider_dequeued(amount);
+        Some(result)
+    }
+
+    /// Dequeue and discard items for as long as they satisfy a predicate, and return how many
+    /// items were dequeued. The first item that does not satisfy the predicate remains at the
+    /// front of the queue. To keep the dequeued items, collect them in the predicate.
+    ///
+    /// #### Implementation Notes
+    ///
+    /// The default implementation orchestrates `expose_items` and `consider_dequeued`, calling
+    /// the predicate on each exposed item until it returns `false`.
+    fn dequeue_while<F: FnMut(&Self::Item) -> bool>(&mut self, mut pred: F) -> usize
+    where
+        Self: Sized,
+    {
+        let mut count = 0;
+
+        while let Some(items) = self.expose_items() {
+            let len = items.len();
+            let amount = items.iter().take_while(|item| pred(item)).count();
+
+            self.consider_dequeued(amount);
+            count += amount;
+
+            if amount < len {
+                break;
+            }
+        }
+
+        count
+    }
+
     /// Dequeue a non-zero number of items by writing them into a given buffer and returning how
     /// many items were dequeued.
     ///
@@ -154,6 +607,7 @@ pub trait Queue {
     /// The default implementation orchestrates `expose_items` and `consider_dequeued` in a
     /// straightforward manner. Only provide your own implementation if you can do better
     /// than that.
+    #[must_use = "fewer items than the buffer holds might have been dequeued"]
     fn bulk_dequeue(&mut self, buffer: &mut [Self::Item]) -> usize {
         match self.expose_items() {
             None => 0,
@@ -167,6 +621,58 @@ pub trait Queue {
         }
     }
 
+    /// Dequeue exactly as many items as fit into a given buffer, or none at all, and return
+    /// whether the items were dequeued. Useful for reading fixed-size records.
+    ///
+    /// Will return `false` and leave both the queue and the buffer unchanged if the queue contains
+    /// fewer than `buffer.len()` items at the time of calling.
+    ///
+    /// #### Implementation Notes
+    ///
+    /// The default implementation checks `len` and then calls `bulk_dequeue` until the buffer is
+    /// full.
+    #[must_use = "the buffer is left unchanged if the queue holds too few items"]
+    fn bulk_dequeue_exact(&mut self, buffer: &mut [Self::Item]) -> bool {
+        if self.len() < buffer.len() {
+            false
+        } else {
+            let mut filled = 0;
+            while filled < buffer.len() {
+                let amount = self.bulk_dequeue(&mut buffer[filled..]);
+                debug_assert!(amount > 0, "queue ran out of items despite its length");
+                filled += amount;
+            }
+
+            true
+        }
+    }
+
+    /// Dequeue items into several buffers in order, filling each buffer before moving on to the
+    /// next one, until either all buffers are full or the queue is empty. Return the total number
+    /// of items that were dequeued. Useful for scatter-gather consumers.
+    ///
+    /// #### Implementation Notes
+    ///
+    /// The default implementation calls `bulk_dequeue` until the buffers are full or the queue
+    /// is empty.
+    fn bulk_dequeue_vectored(&mut self, bufs: &mut [&mut [Self::Item]]) -> usize {
+        let mut count = 0;
+
+        for buf in bufs.iter_mut() {
+            let mut filled = 0;
+            while filled < buf.len() {
+                let amount = self.bulk_dequeue(&mut buf[filled..]);
+                if amount == 0 {
+                    return count;
+                }
+                filled += amount;
+                count += amount;
+            }
+        }
+
+        count
+    }
+
     /// Dequeue a non-zero number of items by writing them into a given buffer of possible
     /// uninitialised memory and returning how many items were dequeued.
     ///
@@ -177,16 +683,373 @@ pub trait Queue {
     /// The default implementation orchestrates `expose_items` and `consider_dequeued` in a
     /// straightforward manner. Only provide your own implementation if you can do better
     /// than that.
+    #[must_use = "only as many items of the buffer as returned are initialised"]
     fn bulk_dequeue_uninit(&mut self, buffer: &mut [MaybeUninit<Self::Item>]) -> usize {
         match self.expose_items() {
             None => 0,
             Some(slots) => {
                 let amount = min(slots.len(), buffer.len());
-                MaybeUninit::copy_from_slice(&mut buffer[..amount], &slots[..amount]);
+                buffer[..amount].write_copy_of_slice(&slots[..amount]);
                 self.consider_dequeued(amount);
 
                 amount
             }
         }
     }
+
+    /// Dequeue exactly as many items as fit into a given buffer of possibly uninitialised memory,
+    /// or none at all, and return whether the items were dequeued. If so, the whole buffer is
+    /// initialised afterwards.
+    ///
+    /// Will return `false` and leave both the queue and the buffer unchanged if the queue contains
+    /// fewer than `buffer.len()` items at the time of calling.
+    ///
+    /// #### Implementation Notes
+    ///
+    /// The default implementation checks `len` and then calls `bulk_dequeue_uninit` until the
+    /// buffer is full.
+    #[must_use = "the buffer is left uninitialised if the queue holds too few items"]
+    fn bulk_dequeue_exact_uninit(&mut self, buffer: &mut [MaybeUninit<Self::Item>]) -> bool {
+        if self.len() < buffer.len() {
+            false
+        } else {
+            let mut filled = 0;
+            while filled < buffer.len() {
+                let amount = self.bulk_dequeue_uninit(&mut buffer[filled..]);
+                debug_assert!(amount > 0, "queue ran out of items despite its length");
+                filled += amount;
+            }
+
+            true
+        }
+    }
+}
+
+/// Forwards every method to the borrowed queue, so that generic code taking a queue by value can
+/// be called with a mutable reference instead, like `std::io::Write` for `&mut W`. The methods
+/// that require `Self: Sized` use their default implementations, since the borrowed queue might
+/// be a trait object.
+impl<Q: Queue + ?Sized> Queue for &mut Q {
+    type Item = Q::Item;
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+
+    fn capacity(&self) -> usize {
+        (**self).capacity()
+    }
+
+    fn remaining(&self) -> usize {
+        (**self).remaining()
+    }
+
+    fn is_full(&self) -> bool {
+        (**self).is_full()
+    }
+
+    fn enqueue(&mut self, item: Self::Item) -> Option<Self::Item> {
+        (**self).enqueue(item)
+    }
+
+    fn try_enqueue(&mut self, item: Self::Item) -> Result<(), QueueFull<Self::Item>> {
+        (**self).try_enqueue(item)
+    }
+
+    fn enqueue_evicting(&mut self, item: Self::Item) -> Option<Self::Item> {
+        (**self).enqueue_evicting(item)
+    }
+
+    fn expose_slots(&mut self) -> Option<&mut [MaybeUninit<Self::Item>]> {
+        (**self).expose_slots()
+    }
+
+    unsafe fn consider_enqueued(&mut self, amount: usize) {
+        unsafe { (**self).consider_enqueued(amount) }
+    }
+
+    fn bulk_enqueue(&mut self, buffer: &[Self::Item]) -> usize {
+        (**self).bulk_enqueue(buffer)
+    }
+
+    fn bulk_enqueue_remainder<'a>(&mut self, buffer: &'a [Self::Item]) -> &'a [Self::Item] {
+        (**self).bulk_enqueue_remainder(buffer)
+    }
+
+    fn bulk_enqueue_all_or_none(&mut self, buffer: &[Self::Item]) -> bool {
+        (**self).bulk_enqueue_all_or_none(buffer)
+    }
+
+    fn bulk_enqueue_vectored(&mut self, bufs: &[&[Self::Item]]) -> usize {
+        (**self).bulk_enqueue_vectored(bufs)
+    }
+
+    fn fill_with_pattern(&mut self, pattern: &[Self::Item]) -> usize {
+        (**self).fill_with_pattern(pattern)
+    }
+
+    fn dequeue(&mut self) -> Option<Self::Item> {
+        (**self).dequeue()
+    }
+
+    fn peek(&self) -> Option<Self::Item> {
+        (**self).peek()
+    }
+
+    fn last(&self) -> Option<Self::Item> {
+        (**self).last()
+    }
+
+    fn expose_items(&mut self) -> Option<&[Self::Item]> {
+        (**self).expose_items()
+    }
+
+    fn consider_dequeued(&mut self, amount: usize) {
+        (**self).consider_dequeued(amount)
+    }
+
+    fn bulk_dequeue(&mut self, buffer: &mut [Self::Item]) -> usize {
+        (**self).bulk_dequeue(buffer)
+    }
+
+    fn bulk_dequeue_exact(&mut self, buffer: &mut [Self::Item]) -> bool {
+        (**self).bulk_dequeue_exact(buffer)
+    }
+
+    fn bulk_dequeue_vectored(&mut self, bufs: &mut [&mut [Self::Item]]) -> usize {
+        (**self).bulk_dequeue_vectored(bufs)
+    }
+
+    fn bulk_dequeue_uninit(&mut self, buffer: &mut [MaybeUninit<Self::Item>]) -> usize {
+        (**self).bulk_dequeue_uninit(buffer)
+    }
+
+    fn bulk_dequeue_exact_uninit(&mut self, buffer: &mut [MaybeUninit<Self::Item>]) -> bool {
+        (**self).bulk_dequeue_exact_uninit(buffer)
+    }
+}
+
+/// Forwards every method to the boxed queue, so that queues of different types can be stored as
+/// `Box<dyn Queue<Item = T>>` and still be used as queues. The methods that require
+/// `Self: Sized` use their default implementations, since the boxed queue might be a trait object.
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<Q: Queue + ?Sized> Queue for alloc::boxed::Box<Q> {
+    type Item = Q::Item;
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+
+    fn capacity(&self) -> usize {
+        (**self).capacity()
+    }
+
+    fn remaining(&self) -> usize {
+        (**self).remaining()
+    }
+
+    fn is_full(&self) -> bool {
+        (**self).is_full()
+    }
+
+    fn enqueue(&mut self, item: Self::Item) -> Option<Self::Item> {
+        (**self).enqueue(item)
+    }
+
+    fn try_enqueue(&mut self, item: Self::Item) -> Result<(), QueueFull<Self::Item>> {
+        (**self).try_enqueue(item)
+    }
+
+    fn enqueue_evicting(&mut self, item: Self::Item) -> Option<Self::Item> {
+        (**self).enqueue_evicting(item)
+    }
+
+    fn expose_slots(&mut self) -> Option<&mut [MaybeUninit<Self::Item>]> {
+        (**self).expose_slots()
+    }
+
+    unsafe fn consider_enqueued(&mut self, amount: usize) {
+        unsafe { (**self).consider_enqueued(amount) }
+    }
+
+    fn bulk_enqueue(&mut self, buffer: &[Self::Item]) -> usize {
+        (**self).bulk_enqueue(buffer)
+    }
+
+    fn bulk_enqueue_remainder<'a>(&mut self, buffer: &'a [Self::Item]) -> &'a [Self::Item] {
+        (**self).bulk_enqueue_remainder(buffer)
+    }
+
+    fn bulk_enqueue_all_or_none(&mut self, buffer: &[Self::Item]) -> bool {
+        (**self).bulk_enqueue_all_or_none(buffer)
+    }
+
+    fn bulk_enqueue_vectored(&mut self, bufs: &[&[Self::Item]]) -> usize {
+        (**self).bulk_enqueue_vectored(bufs)
+    }
+
+    fn fill_with_pattern(&mut self, pattern: &[Self::Item]) -> usize {
+        (**self).fill_with_pattern(pattern)
+    }
+
+    fn dequeue(&mut self) -> Option<Self::Item> {
+        (**self).dequeue()
+    }
+
+    fn peek(&self) -> Option<Self::Item> {
+        (**self).peek()
+    }
+
+    fn last(&self) -> Option<Self::Item> {
+        (**self).last()
+    }
+
+    fn expose_items(&mut self) -> Option<&[Self::Item]> {
+        (**self).expose_items()
+    }
+
+    fn consider_dequeued(&mut self, amount: usize) {
+        (**self).consider_dequeued(amount)
+    }
+
+    fn bulk_dequeue(&mut self, buffer: &mut [Self::Item]) -> usize {
+        (**self).bulk_dequeue(buffer)
+    }
+
+    fn bulk_dequeue_exact(&mut self, buffer: &mut [Self::Item]) -> bool {
+        (**self).bulk_dequeue_exact(buffer)
+    }
+
+    fn bulk_dequeue_vectored(&mut self, bufs: &mut [&mut [Self::Item]]) -> usize {
+        (**self).bulk_dequeue_vectored(bufs)
+    }
+
+    fn bulk_dequeue_uninit(&mut self, buffer: &mut [MaybeUninit<Self::Item>]) -> usize {
+        (**self).bulk_dequeue_uninit(buffer)
+    }
+
+    fn bulk_dequeue_exact_uninit(&mut self, buffer: &mut [MaybeUninit<Self::Item>]) -> bool {
+        (**self).bulk_dequeue_exact_uninit(buffer)
+    }
+}
+
+/// Move up to `max` items from the front of `src` to the back of `dst`, and return how many items
+/// were moved. Stops early if `src` becomes empty or `dst` becomes full.
+///
+/// The items are copied directly from the exposed items of `src` into the exposed slots of
+/// `dst`, one pair of segments at a time, without an intermediate buffer. Unlike
+/// [`Queue::bulk_enqueue_from`], this keeps going across the ends of both ring-buffers.
+pub fn transfer<S: Queue, D: Queue<Item = S::Item>>(src: &mut S, dst: &mut D, max: usize) -> usize {
+    let mut count = 0;
+
+    while count < max {
+        let amount = match src.expose_items() {
+            None => 0,
+            Some(items) => match dst.expose_slots() {
+                None => 0,
+                Some(slots) => {
+                    let amount = min(min(slots.len(), items.len()), max - count);
+                    slots[..amount].write_copy_of_slice(&items[..amount]);
+
+                    amount
+                }
+            },
+        };
+
+        if amount == 0 {
+            break;
+        }
+
+        unsafe {
+            dst.consider_enqueued(amount);
+        }
+        src.consider_dequeued(amount);
+        count += amount;
+    }
+
+    count
+}
+
+/// Formats the items of a queue as a list, given as the two segments of its ring-buffer in FIFO order.
+struct DebugItems<'a, T>(&'a [T], &'a [T]);
+
+impl<T: fmt::Debug> fmt::Debug for DebugItems<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0).entries(self.1).finish()
+    }
+}
+
+/// The error returned by [`Queue::try_enqueue`] when the queue is full. Carries the item that
+/// could not be enqueued.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QueueFull<T>(pub T);
+
+impl<T> QueueFull<T> {
+    /// Return the item that could not be enqueued.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> fmt::Display for QueueFull<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot enqueue into a full queue")
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for QueueFull<T> {}
+
+/// Convenience methods for any [`Queue`] that rely on dynamic memory allocation. Gated behind the `std` or `alloc` feature.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub trait QueueExt: Queue {
+    /// Dequeue all items currently in the queue into a freshly allocated [`Vec`](alloc::vec::Vec), in FIFO order.
+    fn dequeue_all(&mut self) -> alloc::vec::Vec<Self::Item> {
+        self.dequeue_up_to(self.len())
+    }
+
+    /// Dequeue up to `n` items into a freshly allocated [`Vec`](alloc::vec::Vec), in FIFO order.
+    /// Dequeues fewer items only if the queue contains fewer than `n` items.
+    fn dequeue_up_to(&mut self, n: usize) -> alloc::vec::Vec<Self::Item> {
+        let target = min(n, self.len());
+        let mut items = alloc::vec::Vec::with_capacity(target);
+
+        while items.len() < target {
+            let len = items.len();
+            let amount = self.bulk_dequeue_uninit(&mut items.spare_capacity_mut()[..target - len]);
+            if amount == 0 {
+                break;
+            }
+
+            unsafe {
+                items.set_len(len + amount);
+            }
+        }
+
+        items
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<Q: Queue + ?Sized> QueueExt for Q {}
+
+/// A [`Queue`] that additionally supports enqueueing items at its front and dequeueing items
+/// from its back, making it a [double-ended queue](https://en.wikipedia.org/wiki/Double-ended_queue).
+pub trait Deque: Queue {
+    /// Attempt to enqueue an item at the front of the queue, so that it is the next item to be dequeued.
+    ///
+    /// Will return the item instead of enqueueing it if the queue is full at the time of calling.
+    fn push_front(&mut self, item: Self::Item) -> Option<Self::Item>;
+
+    /// Attempt to remove the most recently enqueued item from the back of the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn pop_back(&mut self) -> Option<Self::Item>;
 }
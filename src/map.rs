@@ -0,0 +1,300 @@
+use core::fmt;
+use core::mem::MaybeUninit;
+
+use crate::Queue;
+
+/// A queue adaptor that converts between the items of an inner queue and a different item type,
+/// for example a `#[repr(transparent)]` wrapper. Enqueued items are converted with `to_inner`
+/// before being enqueued into the inner queue, and dequeued items are converted with
+/// `from_inner` after being dequeued from it.
+///
+/// The bulk methods convert the items one by one, directly between the given buffer and the
+/// memory of the inner queue. Since the inner queue holds no items of the outer type, the
+/// low-level methods [`expose_slots`](Queue::expose_slots) and
+/// [`expose_items`](Queue::expose_items) expose only a single slot or item at a time, which
+/// the adaptor stores itself.
+pub struct Map<Q: Queue, Out, F, G> {
+    inner: Q,
+    to_inner: F,
+    from_inner: G,
+    /// Slot exposed by `expose_slots`.
+    slot: MaybeUninit<Out>,
+    /// Converted item exposed by `expose_items`.
+    front: Option<Out>,
+}
+
+impl<Q, Out, F, G> Map<Q, Out, F, G>
+where
+    Q: Queue,
+    Out: Copy,
+    F: Fn(Out) -> Q::Item,
+    G: Fn(Q::Item) -> Out,
+{
+    /// Wrap a queue, converting items with `to_inner` when enqueueing them and with `from_inner`
+    /// when dequeueing them.
+    pub fn new(inner: Q, to_inner: F, from_inner: G) -> Self {
+        Map {
+            inner,
+            to_inner,
+            from_inner,
+            slot: MaybeUninit::uninit(),
+            front: None,
+        }
+    }
+
+    /// Return a reference to the inner queue.
+    pub fn get_ref(&self) -> &Q {
+        &self.inner
+    }
+
+    /// Unwrap the inner queue, discarding the conversion functions.
+    pub fn into_inner(self) -> Q {
+        self.inner
+    }
+}
+
+impl<Q, Out, F, G> Queue for Map<Q, Out, F, G>
+where
+    Q: Queue,
+    Out: Copy,
+    F: Fn(Out) -> Q::Item,
+    G: Fn(Q::Item) -> Out,
+{
+    type Item = Out;
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    fn remaining(&self) -> usize {
+        self.inner.remaining()
+    }
+
+    fn is_full(&self) -> bool {
+        self.inner.is_full()
+    }
+
+    fn enqueue(&mut self, item: Out) -> Option<Out> {
+        self.inner.enqueue((self.to_inner)(item)).map(|_| item)
+    }
+
+    /// Expose a single slot for the client code to fill with an item that should be enqueued.
+    ///
+    /// Will return `None` if the queue is full at the time of calling.
+    fn expose_slots(&mut self) -> Option<&mut [MaybeUninit<Out>]> {
+        if self.inner.is_full() {
+            None
+        } else {
+            Some(core::slice::from_mut(&mut self.slot))
+        }
+    }
+
+    /// Inform the queue that `amount` many items have been written to the slot it has most
+    /// recently exposed, which converts and enqueues the item if `amount` is one.
+    ///
+    /// #### Invariants
+    ///
+    /// Callers must have written into (at least) the `amount` many first `expose_slots` that
+    /// were most recently exposed. Failure to uphold this invariant may cause undefined behavior.
+    ///
+    /// #### Safety
+    ///
+    /// The queue will assume the first `amount` many `expose_slots` that were most recently
+    /// exposed to contain initialized memory after this call, even if the memory it exposed was
+    /// originally uninitialized. Violating the invariants will cause the queue to read undefined
+    /// memory, which triggers undefined behavior.
+    unsafe fn consider_enqueued(&mut self, amount: usize) {
+        debug_assert!(
+            amount <= 1,
+            "cannot consider more slots enqueued than were exposed"
+        );
+
+        if amount > 0 {
+            let item = unsafe { self.slot.assume_init() };
+            let rejected = self.inner.enqueue((self.to_inner)(item));
+            debug_assert!(rejected.is_none(), "queue rejected item despite free slots");
+        }
+    }
+
+    /// Enqueue a non-zero number of items by converting them from a given buffer and returning
+    /// how many items were enqueued.
+    ///
+    /// Will return `0` if the queue is full at the time of calling.
+    fn bulk_enqueue(&mut self, buffer: &[Out]) -> usize {
+        match self.inner.expose_slots() {
+            None => 0,
+            Some(slots) => {
+                let amount = core::cmp::min(slots.len(), buffer.len());
+                for (slot, item) in slots.iter_mut().zip(&buffer[..amount]) {
+                    slot.write((self.to_inner)(*item));
+                }
+
+                unsafe {
+                    self.inner.consider_enqueued(amount);
+                }
+
+                amount
+            }
+        }
+    }
+
+    fn dequeue(&mut self) -> Option<Out> {
+        self.inner.dequeue().map(&self.from_inner)
+    }
+
+    fn peek(&self) -> Option<Out> {
+        self.inner.peek().map(&self.from_inner)
+    }
+
+    fn last(&self) -> Option<Out> {
+        self.inner.last().map(&self.from_inner)
+    }
+
+    /// Expose the next item to be dequeued, converted to the outer item type, as a slice of
+    /// length one.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn expose_items(&mut self) -> Option<&[Out]> {
+        let item = self.inner.expose_items()?[0];
+        self.front = Some((self.from_inner)(item));
+
+        Some(self.front.as_slice())
+    }
+
+    fn consider_dequeued(&mut self, amount: usize) {
+        debug_assert!(
+            amount <= 1,
+            "cannot consider more items dequeued than were exposed"
+        );
+
+        self.inner.consider_dequeued(amount);
+    }
+
+    /// Dequeue a non-zero number of items by converting them into a given buffer and returning
+    /// how many items were dequeued.
+    ///
+    /// Will return `0` if the queue is empty at the time of calling.
+    fn bulk_dequeue(&mut self, buffer: &mut [Out]) -> usize {
+        match self.inner.expose_items() {
+            None => 0,
+            Some(items) => {
+                let amount = core::cmp::min(items.len(), buffer.len());
+                for (slot, item) in buffer.iter_mut().zip(&items[..amount]) {
+                    *slot = (self.from_inner)(*item);
+                }
+                self.inner.consider_dequeued(amount);
+
+                amount
+            }
+        }
+    }
+
+    /// Dequeue a non-zero number of items by converting them into a given buffer of possibly
+    /// uninitialised memory and returning how many items were dequeued.
+    ///
+    /// Will return `0` if the queue is empty at the time of calling.
+    fn bulk_dequeue_uninit(&mut self, buffer: &mut [MaybeUninit<Out>]) -> usize {
+        match self.inner.expose_items() {
+            None => 0,
+            Some(items) => {
+                let amount = core::cmp::min(items.len(), buffer.len());
+                for (slot, item) in buffer.iter_mut().zip(&items[..amount]) {
+                    slot.write((self.from_inner)(*item));
+                }
+                self.inner.consider_dequeued(amount);
+
+                amount
+            }
+        }
+    }
+}
+
+impl<Q: Queue + fmt::Debug, Out, F, G> fmt::Debug for Map<Q, Out, F, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Map").field("inner", &self.inner).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::format;
+
+    use super::*;
+    use crate::Static;
+
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct MyByte(u8);
+
+    fn wrap(inner: Static<u8, 4>) -> impl Queue<Item = MyByte> {
+        Map::new(inner, |byte: MyByte| byte.0, MyByte)
+    }
+
+    #[test]
+    fn maps_items_in_fifo_order() {
+        let mut queue = wrap(Static::new());
+
+        assert_eq!(queue.enqueue(MyByte(1)), None);
+        assert_eq!(queue.bulk_enqueue(&[MyByte(2), MyByte(3), MyByte(4)]), 3);
+        assert_eq!(queue.enqueue(MyByte(5)), Some(MyByte(5)));
+        assert_eq!(queue.peek(), Some(MyByte(1)));
+        assert_eq!(queue.last(), Some(MyByte(4)));
+
+        assert_eq!(queue.dequeue(), Some(MyByte(1)));
+        // This item wraps around the end of the ring-buffer.
+        assert!(queue.bulk_enqueue_all_or_none(&[MyByte(5)]));
+
+        let mut buf = [MyByte(0); 2];
+        assert_eq!(queue.bulk_dequeue(&mut buf), 2);
+        assert_eq!(buf, [MyByte(2), MyByte(3)]);
+
+        let mut uninit_buf = [MaybeUninit::uninit(); 2];
+        assert_eq!(queue.bulk_dequeue_uninit(&mut uninit_buf), 1);
+        assert_eq!(unsafe { uninit_buf[0].assume_init() }, MyByte(4));
+        assert_eq!(queue.dequeue(), Some(MyByte(5)));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn exposes_single_slots_and_items() {
+        let mut queue = Map::new(Static::<u8, 2>::new(), |byte: MyByte| byte.0, MyByte);
+
+        // Filling with a pattern goes through the exposed single slots.
+        assert_eq!(queue.fill_with_pattern(&[MyByte(1), MyByte(2)]), 2);
+        assert_eq!(queue.get_ref().peek(), Some(1));
+        assert_eq!(queue.bulk_enqueue_remainder(&[MyByte(3)]), &[MyByte(3)]);
+
+        assert_eq!(queue.expose_items(), Some(&[MyByte(1)][..]));
+        queue.consider_dequeued(1);
+        assert_eq!(
+            queue.enqueue_slots_with(|writer| {
+                assert_eq!(writer.push(MyByte(7)), None);
+                assert_eq!(writer.push(MyByte(8)), Some(MyByte(8)));
+            }),
+            1
+        );
+
+        assert_eq!(queue.into_inner().last(), Some(7));
+    }
+
+    #[test]
+    fn test_debug_impl() {
+        let mut queue = Map::new(Static::<u8, 2>::new(), |byte: MyByte| byte.0, MyByte);
+
+        assert_eq!(queue.enqueue(MyByte(7)), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Map { inner: Static { len: 1, data: [7] } }"
+        );
+    }
+}
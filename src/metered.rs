@@ -0,0 +1,290 @@
+use core::fmt;
+use core::mem::MaybeUninit;
+
+use crate::{Queue, QueueFull, SlotWriter};
+
+/// A queue adaptor that records statistics about an inner queue: the largest number of items it
+/// held at any time, and the total numbers of items enqueued and dequeued over its lifetime.
+///
+/// Every method that enqueues or dequeues items updates the statistics, so the adaptor only adds
+/// a few counter updates to each call.
+pub struct Metered<Q> {
+    inner: Q,
+    peak_len: usize,
+    enqueued_total: u64,
+    dequeued_total: u64,
+}
+
+impl<Q: Queue> Metered<Q> {
+    /// Wrap a queue to record statistics about it. Items already in the queue count towards its
+    /// peak length, but not towards the total number of enqueued items.
+    pub fn new(inner: Q) -> Self {
+        Metered {
+            peak_len: inner.len(),
+            inner,
+            enqueued_total: 0,
+            dequeued_total: 0,
+        }
+    }
+
+    /// Return the largest number of items the queue has held at the same time.
+    pub fn peak_len(&self) -> usize {
+        self.peak_len
+    }
+
+    /// Return the total number of items enqueued into the queue.
+    pub fn enqueued_total(&self) -> u64 {
+        self.enqueued_total
+    }
+
+    /// Return the total number of items dequeued from the queue.
+    pub fn dequeued_total(&self) -> u64 {
+        self.dequeued_total
+    }
+
+    /// Return a reference to the inner queue.
+    pub fn get_ref(&self) -> &Q {
+        &self.inner
+    }
+
+    /// Unwrap the inner queue, discarding the statistics.
+    pub fn into_inner(self) -> Q {
+        self.inner
+    }
+
+    fn record_enqueued(&mut self, amount: usize) {
+        self.enqueued_total += amount as u64;
+        self.peak_len = self.peak_len.max(self.inner.len());
+    }
+
+    fn record_dequeued(&mut self, amount: usize) {
+        self.dequeued_total += amount as u64;
+    }
+}
+
+impl<Q: Queue> Queue for Metered<Q> {
+    type Item = Q::Item;
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    fn remaining(&self) -> usize {
+        self.inner.remaining()
+    }
+
+    fn is_full(&self) -> bool {
+        self.inner.is_full()
+    }
+
+    fn enqueue(&mut self, item: Self::Item) -> Option<Self::Item> {
+        let result = self.inner.enqueue(item);
+        if result.is_none() {
+            self.record_enqueued(1);
+        }
+
+        result
+    }
+
+    fn try_enqueue(&mut self, item: Self::Item) -> Result<(), QueueFull<Self::Item>> {
+        let result = self.inner.try_enqueue(item);
+        if result.is_ok() {
+            self.record_enqueued(1);
+        }
+
+        result
+    }
+
+    fn expose_slots(&mut self) -> Option<&mut [MaybeUninit<Self::Item>]> {
+        self.inner.expose_slots()
+    }
+
+    unsafe fn consider_enqueued(&mut self, amount: usize) {
+        unsafe { self.inner.consider_enqueued(amount) };
+        self.record_enqueued(amount);
+    }
+
+    fn enqueue_slots_with<F: FnOnce(&mut SlotWriter<'_, Self::Item>)>(&mut self, f: F) -> usize {
+        let amount = self.inner.enqueue_slots_with(f);
+        self.record_enqueued(amount);
+
+        amount
+    }
+
+    fn bulk_enqueue(&mut self, buffer: &[Self::Item]) -> usize {
+        let amount = self.inner.bulk_enqueue(buffer);
+        self.record_enqueued(amount);
+
+        amount
+    }
+
+    fn bulk_enqueue_remainder<'a>(&mut self, buffer: &'a [Self::Item]) -> &'a [Self::Item] {
+        let remainder = self.inner.bulk_enqueue_remainder(buffer);
+        self.record_enqueued(buffer.len() - remainder.len());
+
+        remainder
+    }
+
+    fn bulk_enqueue_all_or_none(&mut self, buffer: &[Self::Item]) -> bool {
+        let enqueued = self.inner.bulk_enqueue_all_or_none(buffer);
+        if enqueued {
+            self.record_enqueued(buffer.len());
+        }
+
+        enqueued
+    }
+
+    fn fill_with_pattern(&mut self, pattern: &[Self::Item]) -> usize {
+        let amount = self.inner.fill_with_pattern(pattern);
+        self.record_enqueued(amount);
+
+        amount
+    }
+
+    fn bulk_enqueue_from<P: Queue<Item = Self::Item>>(&mut self, other: &mut P) -> usize {
+        let amount = self.inner.bulk_enqueue_from(other);
+        self.record_enqueued(amount);
+
+        amount
+    }
+
+    fn enqueue_from_iter<I: Iterator<Item = Self::Item>>(&mut self, iter: &mut I) -> usize {
+        let amount = self.inner.enqueue_from_iter(iter);
+        self.record_enqueued(amount);
+
+        amount
+    }
+
+    fn dequeue(&mut self) -> Option<Self::Item> {
+        let item = self.inner.dequeue()?;
+        self.record_dequeued(1);
+
+        Some(item)
+    }
+
+    fn peek(&self) -> Option<Self::Item> {
+        self.inner.peek()
+    }
+
+    fn last(&self) -> Option<Self::Item> {
+        self.inner.last()
+    }
+
+    fn expose_items(&mut self) -> Option<&[Self::Item]> {
+        self.inner.expose_items()
+    }
+
+    fn consider_dequeued(&mut self, amount: usize) {
+        self.inner.consider_dequeued(amount);
+        self.record_dequeued(amount);
+    }
+
+    fn bulk_dequeue(&mut self, buffer: &mut [Self::Item]) -> usize {
+        let amount = self.inner.bulk_dequeue(buffer);
+        self.record_dequeued(amount);
+
+        amount
+    }
+
+    fn bulk_dequeue_uninit(&mut self, buffer: &mut [MaybeUninit<Self::Item>]) -> usize {
+        let amount = self.inner.bulk_dequeue_uninit(buffer);
+        self.record_dequeued(amount);
+
+        amount
+    }
+}
+
+impl<Q: fmt::Debug> fmt::Debug for Metered<Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Metered")
+            .field("peak_len", &self.peak_len)
+            .field("enqueued_total", &self.enqueued_total)
+            .field("dequeued_total", &self.dequeued_total)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::format;
+
+    use super::*;
+    use crate::Static;
+
+    #[test]
+    fn records_peak_and_totals() {
+        let mut queue = Metered::new(Static::<u8, 8>::new());
+        assert_eq!(queue.peak_len(), 0);
+
+        assert_eq!(queue.enqueue(1), None);
+        assert_eq!(queue.try_enqueue(2), Ok(()));
+        assert_eq!(queue.bulk_enqueue(&[3, 4, 5]), 3);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+        assert_eq!(queue.peak_len(), 5);
+
+        assert_eq!(
+            queue.bulk_enqueue_remainder(&[6, 7, 8, 9, 10, 11, 12, 13]),
+            &[13]
+        );
+        assert_eq!(queue.peak_len(), 8);
+        assert!(!queue.bulk_enqueue_all_or_none(&[14]));
+        assert_eq!(queue.enqueue(14), Some(14));
+
+        let mut uninit_buf = [MaybeUninit::uninit(); 4];
+        assert_eq!(queue.bulk_dequeue_uninit(&mut uninit_buf), 4);
+        assert_eq!(
+            queue.dequeue_items_with(|items| (items.len(), ())),
+            Some(())
+        );
+        assert!(queue.is_empty());
+
+        let mut other = Static::<u8, 4>::new();
+        assert_eq!(other.bulk_enqueue(&[15, 16]), 2);
+        assert_eq!(queue.bulk_enqueue_from(&mut other), 2);
+        assert_eq!(queue.fill_with_pattern(&[0]), 6);
+        assert_eq!(queue.expose_items().map(|items| items[0]), Some(15));
+        queue.consider_dequeued(1);
+        assert_eq!(queue.enqueue_from_iter(&mut [17, 18].into_iter()), 1);
+        assert_eq!(queue.dequeue(), Some(16));
+
+        assert_eq!(queue.enqueued_total(), 21);
+        assert_eq!(queue.dequeued_total(), 14);
+        assert_eq!(queue.peak_len(), 8);
+        assert_eq!(queue.len(), 7);
+    }
+
+    #[test]
+    fn counts_initial_items_towards_peak_only() {
+        let mut inner = Static::<u8, 4>::new();
+        assert_eq!(inner.bulk_enqueue(&[1, 2, 3]), 3);
+
+        let queue = Metered::new(inner);
+        assert_eq!(queue.peak_len(), 3);
+        assert_eq!(queue.enqueued_total(), 0);
+        assert_eq!(queue.get_ref().len(), 3);
+        assert_eq!(queue.into_inner().len(), 3);
+    }
+
+    #[test]
+    fn test_debug_impl() {
+        let mut queue = Metered::new(Static::<u8, 2>::new());
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Metered { peak_len: 1, enqueued_total: 1, dequeued_total: 0, inner: Static { len: 1, data: [7] } }"
+        );
+    }
+}
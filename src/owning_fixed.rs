@@ -0,0 +1,275 @@
+extern crate alloc;
+
+use crate::allocator::{self, Allocator, Global, Slots};
+
+use core::fmt;
+use core::mem::MaybeUninit;
+
+use crate::DebugItems;
+
+/// A queue holding up to a certain number of owned items. The capacity is set upon
+/// creation and remains fixed. Performs a single heap allocation on creation.
+///
+/// Unlike [Fixed](crate::Fixed), this queue does not require its items to be [`Copy`], and it
+/// runs the destructors of all items it still holds when it is [cleared](OwningFixed::clear)
+/// or dropped. Since items cannot be duplicated by copying memory, it does not implement the
+/// [Queue](crate::Queue) trait with its bulk operations, and only offers item-by-item access.
+pub struct OwningFixed<T, A: Allocator = Global> {
+    /// Slice of memory, used as a ring-buffer.
+    data: Slots<T, A>,
+    /// Read index.
+    read: usize,
+    /// Amount of valid data.
+    amount: usize,
+}
+
+impl<T> OwningFixed<T> {
+    /// Create a fixed-capacity queue. Panic if the initial memory allocation fails.
+    pub fn new(capacity: usize) -> Self {
+        OwningFixed {
+            data: allocator::new_uninit_in(capacity, Global),
+            read: 0,
+            amount: 0,
+        }
+    }
+
+    /// Try to create a fixed-capacity queue. If the initial memory allocation fails, return `None` instead.
+    pub fn try_new(capacity: usize) -> Option<Self> {
+        Some(OwningFixed {
+            data: allocator::try_new_uninit_in(capacity, Global)?,
+            read: 0,
+            amount: 0,
+        })
+    }
+}
+
+impl<T, A: Allocator> OwningFixed<T, A> {
+    /// Create a fixed-capacity queue with a given memory allocator. Panic if the initial memory allocation fails.
+    pub fn new_in(capacity: usize, alloc: A) -> Self {
+        OwningFixed {
+            data: allocator::new_uninit_in(capacity, alloc),
+            read: 0,
+            amount: 0,
+        }
+    }
+
+    /// Try to create a fixed-capacity queue with a given memory allocator. If the initial memory allocation fails, return `None` instead.
+    pub fn try_new_in(capacity: usize, alloc: A) -> Option<Self> {
+        Some(OwningFixed {
+            data: allocator::try_new_uninit_in(capacity, alloc)?,
+            read: 0,
+            amount: 0,
+        })
+    }
+
+    /// Return the capacity with which thise queue was initialised.
+    ///
+    /// The number of free item slots at any time is `q.capacity() - q.len()`.
+    pub fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Return the number of items currently in the queue.
+    pub fn len(&self) -> usize {
+        self.amount
+    }
+
+    /// Return whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.amount == 0
+    }
+
+    /// Attempt to enqueue an item.
+    ///
+    /// Will return the item instead of enqueueing it if the queue is full at the time of calling.
+    pub fn enqueue(&mut self, item: T) -> Option<T> {
+        if self.amount == self.capacity() {
+            Some(item)
+        } else {
+            let write_to = (self.read + self.amount) % self.capacity();
+            self.data[write_to].write(item);
+            self.amount += 1;
+
+            None
+        }
+    }
+
+    /// Attempt to dequeue the next item, handing ownership of it to the caller.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.amount == 0 {
+            None
+        } else {
+            let previous_read = self.read;
+            // Advance the read index by 1 or reset to 0 if at capacity.
+            self.read = (self.read + 1) % self.capacity();
+            self.amount -= 1;
+
+            // The slot is considered uninitialized from now on, so the item is not duplicated.
+            Some(unsafe { self.data[previous_read].assume_init_read() })
+        }
+    }
+
+    /// Return a reference to the next item that would be dequeued, without removing it from the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    pub fn peek(&self) -> Option<&T> {
+        if self.amount == 0 {
+            None
+        } else {
+            Some(unsafe { self.data[self.read].assume_init_ref() })
+        }
+    }
+
+    /// Remove all items from the queue, running their destructors.
+    pub fn clear(&mut self) {
+        let (head, tail) = self.live_slices_mut();
+        unsafe {
+            head.assume_init_drop();
+            tail.assume_init_drop();
+        }
+
+        self.read = 0;
+        self.amount = 0;
+    }
+
+    /// Return the two slices of memory that hold the items of the queue, in FIFO order.
+    fn live_slices_mut(&mut self) -> (&mut [MaybeUninit<T>], &mut [MaybeUninit<T>]) {
+        let head_len = core::cmp::min(self.amount, self.capacity() - self.read);
+        let tail_len = self.amount - head_len;
+
+        let (wrapped, from_read) = self.data.split_at_mut(self.read);
+        (&mut from_read[..head_len], &mut wrapped[..tail_len])
+    }
+
+    /// Return the two slices of memory that hold the items of the queue, in FIFO order.
+    fn live_slices(&self) -> (&[MaybeUninit<T>], &[MaybeUninit<T>]) {
+        let head_len = core::cmp::min(self.amount, self.capacity() - self.read);
+        let tail_len = self.amount - head_len;
+
+        (
+            &self.data[self.read..self.read + head_len],
+            &self.data[..tail_len],
+        )
+    }
+}
+
+impl<T, A: Allocator> Drop for OwningFixed<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for OwningFixed<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (head, tail) = self.live_slices();
+        let (head, tail) = unsafe { (head.assume_init_ref(), tail.assume_init_ref()) };
+
+        f.debug_struct("OwningFixed")
+            .field("capacity", &self.capacity())
+            .field("len", &self.amount)
+            .field("data", &DebugItems(head, tail))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::rc::Rc;
+    use alloc::string::{String, ToString};
+
+    use super::*;
+
+    #[test]
+    fn enqueues_and_dequeues_owned_items() {
+        let mut queue: OwningFixed<String> = OwningFixed::new(2);
+
+        assert_eq!(queue.enqueue("ufo".to_string()), None);
+        assert_eq!(queue.enqueue("tofu".to_string()), None);
+        assert_eq!(queue.len(), 2);
+
+        assert_eq!(queue.enqueue("fu".to_string()), Some("fu".to_string()));
+
+        // Queue should be first-in, first-out.
+        assert_eq!(queue.peek(), Some(&"ufo".to_string()));
+        assert_eq!(queue.dequeue(), Some("ufo".to_string()));
+        assert_eq!(queue.enqueue("fu".to_string()), None);
+        assert_eq!(queue.dequeue(), Some("tofu".to_string()));
+        assert_eq!(queue.dequeue(), Some("fu".to_string()));
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.peek(), None);
+    }
+
+    #[test]
+    fn dequeue_hands_over_ownership() {
+        let item = Rc::new(7);
+        let mut queue = OwningFixed::new(2);
+
+        assert_eq!(queue.enqueue(item.clone()), None);
+        assert_eq!(Rc::strong_count(&item), 2);
+
+        let dequeued = queue.dequeue().unwrap();
+        assert_eq!(Rc::strong_count(&item), 2);
+
+        drop(dequeued);
+        assert_eq!(Rc::strong_count(&item), 1);
+    }
+
+    #[test]
+    fn clear_drops_items() {
+        let item = Rc::new(7);
+        let mut queue = OwningFixed::new(3);
+
+        // Wrap the items around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(item.clone()), None);
+        assert_eq!(queue.enqueue(item.clone()), None);
+        drop(queue.dequeue());
+        drop(queue.dequeue());
+        assert_eq!(queue.enqueue(item.clone()), None);
+        assert_eq!(queue.enqueue(item.clone()), None);
+        assert_eq!(queue.enqueue(item.clone()), None);
+        assert_eq!(Rc::strong_count(&item), 4);
+
+        queue.clear();
+        assert_eq!(Rc::strong_count(&item), 1);
+        assert!(queue.is_empty());
+
+        // The queue remains usable after clearing.
+        assert_eq!(queue.enqueue(item.clone()), None);
+        assert_eq!(queue.peek(), Some(&item));
+    }
+
+    #[test]
+    fn drop_drops_items() {
+        let item = Rc::new(7);
+        let mut queue = OwningFixed::new(2);
+
+        assert_eq!(queue.enqueue(item.clone()), None);
+        assert_eq!(queue.enqueue(item.clone()), None);
+        assert_eq!(Rc::strong_count(&item), 3);
+
+        drop(queue);
+        assert_eq!(Rc::strong_count(&item), 1);
+    }
+
+    #[test]
+    fn test_debug_impl() {
+        let mut queue: OwningFixed<String> = OwningFixed::new(2);
+
+        assert_eq!(queue.enqueue("ufo".to_string()), None);
+        assert_eq!(queue.enqueue("tofu".to_string()), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "OwningFixed { capacity: 2, len: 2, data: [\"ufo\", \"tofu\"] }"
+        );
+
+        let _ = queue.dequeue();
+        assert_eq!(queue.enqueue("fu".to_string()), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "OwningFixed { capacity: 2, len: 2, data: [\"tofu\", \"fu\"] }"
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use core::cmp::min;
+use core::fmt;
+use core::mem::MaybeUninit;
+
+/// Safe write access to the free slots a queue exposes, handed to the closure of
+/// [`Queue::enqueue_slots_with`](crate::Queue::enqueue_slots_with). The writer fills the slots
+/// from the front and keeps track of how many it has written, so the queue enqueues exactly the
+/// initialised slots and never reads uninitialised memory.
+pub struct SlotWriter<'a, T> {
+    slots: &'a mut [MaybeUninit<T>],
+    /// Number of slots at the front of `slots` that have been written to.
+    written: usize,
+}
+
+impl<'a, T: Copy> SlotWriter<'a, T> {
+    pub(crate) fn new(slots: &'a mut [MaybeUninit<T>]) -> Self {
+        SlotWriter { slots, written: 0 }
+    }
+
+    /// Write an item into the next free slot.
+    ///
+    /// Will return the item if all slots have been written to at the time of calling.
+    pub fn push(&mut self, item: T) -> Option<T> {
+        match self.slots.get_mut(self.written) {
+            None => Some(item),
+            Some(slot) => {
+                slot.write(item);
+                self.written += 1;
+
+                None
+            }
+        }
+    }
+
+    /// Write as many items of a given buffer into the next free slots as fit, and return how many
+    /// items were written.
+    pub fn bulk_push(&mut self, buffer: &[T]) -> usize {
+        let amount = min(self.remaining(), buffer.len());
+        self.slots[self.written..self.written + amount].write_copy_of_slice(&buffer[..amount]);
+        self.written += amount;
+
+        amount
+    }
+
+    /// Keep only the first `amount` items written so far, so that only those are enqueued, for
+    /// example when the later ones turn out to be invalid. Further items are written right after
+    /// the kept ones.
+    ///
+    /// Panic if `amount` exceeds the number of items written so far.
+    pub fn commit_enqueued(&mut self, amount: usize) {
+        assert!(
+            amount <= self.written,
+            "cannot commit more items than were written"
+        );
+
+        self.written = amount;
+    }
+
+    /// Return how many slots have been written to.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Return how many slots have not been written to yet.
+    pub fn remaining(&self) -> usize {
+        self.slots.len() - self.written
+    }
+}
+
+impl<T> fmt::Debug for SlotWriter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlotWriter")
+            .field("slots", &self.slots.len())
+            .field("written", &self.written)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn writes_slots_from_the_front() {
+        let mut slots = [MaybeUninit::uninit(); 4];
+        let mut writer = SlotWriter::new(&mut slots);
+
+        assert_eq!(writer.push(1), None);
+        assert_eq!(writer.bulk_push(&[2, 3, 4, 5]), 3);
+        assert_eq!(writer.push(6), Some(6));
+        assert_eq!(writer.written(), 4);
+        assert_eq!(writer.remaining(), 0);
+
+        writer.commit_enqueued(2);
+        assert_eq!(writer.bulk_push(&[7]), 1);
+        assert_eq!(writer.written(), 3);
+        assert_eq!(unsafe { slots[..3].assume_init_ref() }, &[1, 2, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot commit more items than were written")]
+    fn panics_on_committing_more_items_than_were_written() {
+        let mut slots = [MaybeUninit::<u8>::uninit(); 4];
+        let mut writer = SlotWriter::new(&mut slots);
+
+        assert_eq!(writer.push(1), None);
+        writer.commit_enqueued(2);
+    }
+
+    #[test]
+    fn test_debug_impl() {
+        let mut slots = [MaybeUninit::uninit(); 4];
+        let mut writer = SlotWriter::new(&mut slots);
+
+        assert_eq!(writer.push(7), None);
+        assert_eq!(
+            format!("{:?}", writer),
+            "SlotWriter { slots: 4, written: 1 }"
+        );
+    }
+}
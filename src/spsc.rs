@@ -0,0 +1,413 @@
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+
+use core::cell::UnsafeCell;
+use core::cmp::min;
+use core::fmt;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// A lock-free queue for a single producer and a single consumer, which may live on different
+/// threads. The capacity is set upon creation and remains fixed. Performs a single heap
+/// allocation on creation.
+///
+/// Use [`Spsc::split`] to obtain the [`Producer`] and [`Consumer`] handles to interact with
+/// the contents of the queue.
+pub struct Spsc<T> {
+    shared: Shared<T>,
+}
+
+/// The state shared between a [`Producer`] and a [`Consumer`].
+struct Shared<T> {
+    /// Slice of memory, used as a ring-buffer. Holds one more slot than the capacity, so that a
+    /// full queue can be distinguished from an empty one by comparing `read` and `write`.
+    data: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    /// Read index. Only ever modified by the consumer.
+    read: AtomicUsize,
+    /// Write index. Only ever modified by the producer.
+    write: AtomicUsize,
+}
+
+// The producer only accesses slots between `write` and `read`, the consumer only slots between
+// `read` and `write`. Publishing an index with `Release` ordering and loading it with `Acquire`
+// ordering ensures that any slot changes hands only after the previous owner is done with it.
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    fn slots(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Return a pointer to the slot at the given index. The pointer may be used to access the
+    /// following slots as well, up to the end of the ring-buffer.
+    fn slot(&self, index: usize) -> *mut T {
+        assert!(index < self.slots());
+
+        // Deriving the pointer from the whole slice rather than from a reference to a single
+        // slot allows bulk copies starting at this slot.
+        unsafe { UnsafeCell::raw_get(self.data.as_ptr().add(index)) as *mut T }
+    }
+
+    fn len(&self) -> usize {
+        let read = self.read.load(Ordering::Acquire);
+        let write = self.write.load(Ordering::Acquire);
+
+        (write + self.slots() - read) % self.slots()
+    }
+}
+
+impl<T> Spsc<T> {
+    /// Create a single-producer single-consumer queue. Panic if `capacity` is `usize::MAX`, or if
+    /// the initial memory allocation fails.
+    pub fn new(capacity: usize) -> Self {
+        let slots = capacity
+            .checked_add(1)
+            .expect("cannot create a Spsc queue with a capacity of usize::MAX");
+
+        Spsc {
+            shared: Shared {
+                data: (0..slots)
+                    .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+                    .collect(),
+                read: AtomicUsize::new(0),
+                write: AtomicUsize::new(0),
+            },
+        }
+    }
+
+    /// Return the capacity with which this queue was initialised.
+    pub fn capacity(&self) -> usize {
+        self.shared.slots() - 1
+    }
+
+    /// Split the queue into a handle for enqueueing and a handle for dequeueing items. Each
+    /// handle can be moved to a different thread.
+    pub fn split(self) -> (Producer<T>, Consumer<T>) {
+        let shared = Arc::new(self.shared);
+
+        (
+            Producer {
+                shared: shared.clone(),
+            },
+            Consumer { shared },
+        )
+    }
+}
+
+/// The enqueueing half of a [`Spsc`] queue.
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T: Copy> Producer<T> {
+    /// Return the number of items currently in the queue.
+    ///
+    /// When the consumer runs concurrently, the queue may contain fewer items by the time this returns.
+    pub fn len(&self) -> usize {
+        self.shared.len()
+    }
+
+    /// Return whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the capacity with which this queue was initialised.
+    pub fn capacity(&self) -> usize {
+        self.shared.slots() - 1
+    }
+
+    /// Attempt to enqueue an item.
+    ///
+    /// Will return the item instead of enqueueing it if the queue is full at the time of calling.
+    pub fn enqueue(&mut self, item: T) -> Option<T> {
+        let write = self.shared.write.load(Ordering::Relaxed);
+        let next_write = (write + 1) % self.shared.slots();
+
+        if next_write == self.shared.read.load(Ordering::Acquire) {
+            Some(item)
+        } else {
+            unsafe {
+                self.shared.slot(write).write(item);
+            }
+            self.shared.write.store(next_write, Ordering::Release);
+
+            None
+        }
+    }
+
+    /// Enqueue items by reading them from a given buffer and returning how many items were
+    /// enqueued. Enqueues as many items as fit into the queue at the time of calling.
+    ///
+    /// Will return `0` if the queue is full at the time of calling.
+    pub fn bulk_enqueue(&mut self, buffer: &[T]) -> usize {
+        let slots = self.shared.slots();
+        let read = self.shared.read.load(Ordering::Acquire);
+        let mut write = self.shared.write.load(Ordering::Relaxed);
+        let mut amount = 0;
+
+        // At most two iterations: up to the end of the ring-buffer, then from its start.
+        while amount < buffer.len() {
+            // The slot before `read` must stay free.
+            let end = if write < read {
+                read - 1
+            } else if read == 0 {
+                slots - 1
+            } else {
+                slots
+            };
+            let count = min(end - write, buffer.len() - amount);
+            if count == 0 {
+                break;
+            }
+
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    buffer[amount..].as_ptr(),
+                    self.shared.slot(write),
+                    count,
+                );
+            }
+            amount += count;
+            write = (write + count) % slots;
+        }
+
+        self.shared.write.store(write, Ordering::Release);
+        amount
+    }
+}
+
+/// The dequeueing half of a [`Spsc`] queue.
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T: Copy> Consumer<T> {
+    /// Return the number of items currently in the queue.
+    ///
+    /// When the producer runs concurrently, the queue may contain more items by the time this returns.
+    pub fn len(&self) -> usize {
+        self.shared.len()
+    }
+
+    /// Return whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the capacity with which this queue was initialised.
+    pub fn capacity(&self) -> usize {
+        self.shared.slots() - 1
+    }
+
+    /// Attempt to dequeue the next item.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    pub fn dequeue(&mut self) -> Option<T> {
+        let read = self.shared.read.load(Ordering::Relaxed);
+
+        if read == self.shared.write.load(Ordering::Acquire) {
+            None
+        } else {
+            let item = unsafe { self.shared.slot(read).read() };
+            self.shared
+                .read
+                .store((read + 1) % self.shared.slots(), Ordering::Release);
+
+            Some(item)
+        }
+    }
+
+    /// Dequeue items by writing them into a given buffer and returning how many items were
+    /// dequeued. Dequeues as many items as fit into the buffer at the time of calling.
+    ///
+    /// Will return `0` if the queue is empty at the time of calling.
+    pub fn bulk_dequeue(&mut self, buffer: &mut [T]) -> usize {
+        let slots = self.shared.slots();
+        let write = self.shared.write.load(Ordering::Acquire);
+        let mut read = self.shared.read.load(Ordering::Relaxed);
+        let mut amount = 0;
+
+        // At most two iterations: up to the end of the ring-buffer, then from its start.
+        while amount < buffer.len() {
+            let end = if read <= write { write } else { slots };
+            let count = min(end - read, buffer.len() - amount);
+            if count == 0 {
+                break;
+            }
+
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    self.shared.slot(read),
+                    buffer[amount..].as_mut_ptr(),
+                    count,
+                );
+            }
+            amount += count;
+            read = (read + count) % slots;
+        }
+
+        self.shared.read.store(read, Ordering::Release);
+        amount
+    }
+}
+
+impl<T> fmt::Debug for Spsc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Spsc")
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+impl<T> fmt::Debug for Producer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Producer")
+            .field("capacity", &(self.shared.slots() - 1))
+            .field("len", &self.shared.len())
+            .finish()
+    }
+}
+
+impl<T> fmt::Debug for Consumer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Consumer")
+            .field("capacity", &(self.shared.slots() - 1))
+            .field("len", &self.shared.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enqueues_and_dequeues_with_correct_amount() {
+        let (mut producer, mut consumer) = Spsc::new(4).split();
+
+        assert_eq!(producer.enqueue(7), None);
+        assert_eq!(producer.enqueue(21), None);
+        assert_eq!(producer.enqueue(196), None);
+        assert_eq!(producer.len(), 3);
+
+        assert_eq!(producer.enqueue(233), None);
+        assert_eq!(consumer.len(), 4);
+        assert_eq!(producer.enqueue(0), Some(0));
+
+        // Queue should be first-in, first-out.
+        assert_eq!(consumer.dequeue(), Some(7));
+        assert_eq!(consumer.len(), 3);
+    }
+
+    #[test]
+    fn returns_none_on_dequeue_when_queue_is_empty() {
+        let (mut producer, mut consumer) = Spsc::new(1).split();
+        assert_eq!(consumer.dequeue(), None);
+
+        assert_eq!(producer.enqueue(7), None);
+        assert_eq!(consumer.dequeue(), Some(7));
+        assert_eq!(consumer.dequeue(), None);
+    }
+
+    #[test]
+    fn bulk_operations_span_the_end_of_the_ring_buffer() {
+        let (mut producer, mut consumer) = Spsc::new(4).split();
+
+        assert_eq!(producer.bulk_enqueue(&[1, 2, 3]), 3);
+        let mut buf = [0; 2];
+        assert_eq!(consumer.bulk_dequeue(&mut buf), 2);
+        assert_eq!(buf, [1, 2]);
+
+        // Fills the slots up to the end and then from the start of the ring-buffer.
+        assert_eq!(producer.bulk_enqueue(&[4, 5, 6, 7]), 3);
+        assert_eq!(producer.enqueue(7), Some(7));
+
+        let mut buf = [0; 8];
+        assert_eq!(consumer.bulk_dequeue(&mut buf), 4);
+        assert_eq!(buf[..4], [3, 4, 5, 6]);
+        assert_eq!(consumer.bulk_dequeue(&mut buf), 0);
+    }
+
+    #[test]
+    fn capacity_zero_rejects_everything() {
+        let (mut producer, mut consumer) = Spsc::new(0).split();
+
+        assert_eq!(producer.capacity(), 0);
+        assert_eq!(producer.enqueue(7), Some(7));
+        assert_eq!(producer.bulk_enqueue(&[7]), 0);
+        assert_eq!(consumer.dequeue(), None);
+        assert_eq!(consumer.bulk_dequeue(&mut [0]), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot create a Spsc queue with a capacity of usize::MAX")]
+    fn panics_on_capacity_overflow() {
+        let _: Spsc<u8> = Spsc::new(usize::MAX);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn transfers_items_across_threads() {
+        use std::sync::mpsc;
+        use std::thread;
+        use std::vec::Vec;
+
+        const ITEMS: u32 = 20_000;
+
+        let (mut producer, mut consumer) = Spsc::new(37).split();
+        let (control_sender, control_receiver) = mpsc::channel();
+
+        let producer_thread = thread::spawn(move || {
+            let mut next = 0;
+            while next < ITEMS {
+                // Alternate between single and bulk enqueueing.
+                if next % 3 == 0 {
+                    if producer.enqueue(next).is_none() {
+                        control_sender.send(next).unwrap();
+                        next += 1;
+                    } else {
+                        thread::yield_now();
+                    }
+                } else {
+                    let batch: Vec<u32> = (next..min(next + 11, ITEMS)).collect();
+                    let amount = producer.bulk_enqueue(&batch);
+                    for item in &batch[..amount] {
+                        control_sender.send(*item).unwrap();
+                    }
+                    next += amount as u32;
+                    if amount == 0 {
+                        thread::yield_now();
+                    }
+                }
+            }
+        });
+
+        let mut received = 0;
+        let mut buf = [0; 16];
+        while received < ITEMS {
+            if received % 2 == 0 {
+                if let Some(item) = consumer.dequeue() {
+                    assert_eq!(item, control_receiver.recv().unwrap());
+                    received += 1;
+                } else {
+                    thread::yield_now();
+                }
+            } else {
+                let amount = consumer.bulk_dequeue(&mut buf);
+                for item in &buf[..amount] {
+                    assert_eq!(*item, control_receiver.recv().unwrap());
+                }
+                received += amount as u32;
+                if amount == 0 {
+                    thread::yield_now();
+                }
+            }
+        }
+
+        producer_thread.join().unwrap();
+        assert_eq!(consumer.dequeue(), None);
+    }
+}
@@ -1,13 +1,23 @@
 extern crate alloc;
 
+use core::cmp::min;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::mem::MaybeUninit;
+use core::ops::Index;
 
-use crate::Queue;
+use crate::{DebugItems, Deque, Queue};
 
 /// A queue holding up to a certain number of items. The capacity is statically determined by a const parameter. Performs no allocations.
 ///
 /// Use the methods of the [Queue] trait implementation to interact with the contents of the queue.
+///
+/// A queue with `N == 0` is valid: it rejects every item it is asked to enqueue, and never has
+/// any items to dequeue. This allows generic code to treat a capacity of zero like any other.
+/// [`Static::new_checked`] rejects a capacity of zero at compile time instead.
+///
+/// The queue is [`Send`] if `T` is, and [`Sync`] if `T` is. These are derived automatically from
+/// the array it owns, since [`MaybeUninit`] does not affect them.
 pub struct Static<T, const N: usize> {
     /// Buffer of memory, used as a ring-buffer.
     data: [MaybeUninit<T>; N],
@@ -25,7 +35,7 @@ impl<T, const N: usize> Default for Static<T, N> {
 
 impl<T, const N: usize> Static<T, N> {
     /// Create a fixed-capacity queue.
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Static {
             data: [const { MaybeUninit::uninit() }; N],
             read: 0,
@@ -33,6 +43,47 @@ impl<T, const N: usize> Static<T, N> {
         }
     }
 
+    /// Create a fixed-capacity queue, like [`Static::new`], but fail to compile if the capacity
+    /// `N` is zero. Use this where a capacity of zero can only be the result of a mistake, for
+    /// example in computing `N`.
+    ///
+    /// ```compile_fail
+    /// use ufotofu_queues::Static;
+    ///
+    /// let queue: Static<u8, 0> = Static::new_checked();
+    /// ```
+    pub const fn new_checked() -> Self {
+        const {
+            assert!(
+                N > 0,
+                "cannot create a Static queue with a capacity of zero"
+            )
+        };
+
+        Self::new()
+    }
+
+    /// Create a fixed-capacity queue that already contains the given items, with the first item
+    /// of the array being the first one to be dequeued.
+    ///
+    /// Fails to compile if `M` is greater than the capacity `N`.
+    pub fn from_array<const M: usize>(items: [T; M]) -> Self {
+        const {
+            assert!(
+                M <= N,
+                "cannot create a Static queue from an array that exceeds its capacity"
+            )
+        };
+
+        let mut queue = Self::new();
+        for (slot, item) in queue.data.iter_mut().zip(items) {
+            slot.write(item);
+        }
+        queue.amount = M;
+
+        queue
+    }
+
     fn is_data_contiguous(&self) -> bool {
         self.read + self.amount < N
     }
@@ -57,8 +108,486 @@ impl<T, const N: usize> Static<T, N> {
         }
     }
 
+    /// Return the items of the queue as two slices, in FIFO order: the front of the queue
+    /// comes first, and the second slice is empty if the items are stored contiguously.
+    /// Mirrors [`VecDeque::as_slices`](https://doc.rust-lang.org/std/collections/struct.VecDeque.html#method.as_slices).
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (head, tail) = self.live_slices();
+
+        unsafe { (head.assume_init_ref(), tail.assume_init_ref()) }
+    }
+
+    /// Return whether the items of the queue are stored contiguously, that is, whether they do
+    /// not wrap around the end of the ring-buffer. If so, [`expose_items`](Queue::expose_items)
+    /// exposes all items at once, and the second slice of [`as_slices`](Self::as_slices) is
+    /// empty. Otherwise, either dequeue in several steps or call
+    /// [`make_contiguous`](Self::make_contiguous) first.
+    pub fn is_contiguous(&self) -> bool {
+        self.read + self.amount <= N
+    }
+
+    /// Return up to `n` items from the front of the queue as two slices, in FIFO order, without
+    /// dequeueing them. Works like [`as_slices`](Self::as_slices), but limited to at most `n`
+    /// items; the second slice is empty if those items are stored contiguously.
+    pub fn peek_slice(&self, n: usize) -> (&[T], &[T]) {
+        let (head, tail) = self.as_slices();
+        let n = min(n, self.amount);
+
+        if n <= head.len() {
+            (&head[..n], &[])
+        } else {
+            (head, &tail[..n - head.len()])
+        }
+    }
+
+    /// Return the number of items at the front of the queue that satisfy a predicate, up to the
+    /// first item that does not, without dequeueing any of them. Useful for checking whether a
+    /// complete token has arrived before consuming it via
+    /// [`dequeue_while`](Queue::dequeue_while).
+    pub fn peek_while<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        let (head, tail) = self.as_slices();
+        head.iter()
+            .chain(tail)
+            .take_while(|item| pred(item))
+            .count()
+    }
+
+    /// Copy up to `out.len()` items from the front of the queue into `out`, in FIFO order, without
+    /// dequeueing them, and return how many items were copied.
+    pub fn copy_to_slice(&self, out: &mut [T]) -> usize
+    where
+        T: Copy,
+    {
+        let (head, tail) = self.peek_slice(out.len());
+        out[..head.len()].copy_from_slice(head);
+        out[head.len()..head.len() + tail.len()].copy_from_slice(tail);
+
+        head.len() + tail.len()
+    }
+
+    /// Return a mutable reference to the next item that would be dequeued, without removing it
+    /// from the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        if self.amount == 0 {
+            None
+        } else {
+            Some(unsafe { self.data[self.read].assume_init_mut() })
+        }
+    }
+
+    /// Return the items of the queue as two mutable slices, in FIFO order: the front of the queue
+    /// comes first, and the second slice is empty if the items are stored contiguously. This
+    /// allows modifying items in place, without dequeueing and re-enqueueing them.
+    /// Mirrors [`VecDeque::as_mut_slices`](https://doc.rust-lang.org/std/collections/struct.VecDeque.html#method.as_mut_slices).
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let head_len = min(self.amount, N - self.read);
+        let tail_len = self.amount - head_len;
+
+        // The items wrapping around the end of the ring-buffer never reach the read index.
+        let (before_read, from_read) = self.data.split_at_mut(self.read);
+
+        unsafe {
+            (
+                from_read[..head_len].assume_init_mut(),
+                before_read[..tail_len].assume_init_mut(),
+            )
+        }
+    }
+
+    /// Rearrange the items of the queue in place, such that they are stored contiguously
+    /// starting at the beginning of the ring-buffer, and return them as a single slice in FIFO
+    /// order. Mirrors [`VecDeque::make_contiguous`](https://doc.rust-lang.org/std/collections/struct.VecDeque.html#method.make_contiguous).
+    ///
+    /// Afterwards, [`Queue::expose_items`] exposes all items of the queue at once.
+    pub fn make_contiguous(&mut self) -> &[T] {
+        // Rotating moves the uninitialized slots along with the initialized ones, without ever
+        // reading them as `T`.
+        self.data.rotate_left(self.read);
+        self.read = 0;
+
+        unsafe { self.data[..self.amount].assume_init_ref() }
+    }
+
+    /// Consume the queue and return its items as an array in FIFO order, if the queue is full.
+    /// Useful for protocols with fixed-size frames.
+    ///
+    /// Will return `None` if the queue contains fewer than `N` items.
+    pub fn into_array(mut self) -> Option<[T; N]> {
+        if self.amount < N {
+            None
+        } else {
+            self.data.rotate_left(self.read);
+
+            // Every slot holds an item, since the queue is full.
+            Some(self.data.map(|slot| unsafe { slot.assume_init() }))
+        }
+    }
+
+    /// Return whether the queue contains an item equal to the given one.
+    pub fn contains(&self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let (head, tail) = self.as_slices();
+
+        head.contains(item) || tail.contains(item)
+    }
+
+    /// Return the position of the first item for which the predicate returns `true`, counted
+    /// from the front of the queue (so position `0` is the next item that would be dequeued).
+    ///
+    /// Will return `None` if the predicate returns `false` for all items.
+    pub fn position<F: FnMut(&T) -> bool>(&self, pred: F) -> Option<usize> {
+        let (head, tail) = self.as_slices();
+
+        head.iter().chain(tail).position(pred)
+    }
+
+    /// Return the index in the ring-buffer of the next item that would be dequeued.
+    ///
+    /// This is meant for diagnostics only: the layout of the ring-buffer is an implementation
+    /// detail that may change in any release.
+    pub fn read_index(&self) -> usize {
+        self.read
+    }
+
+    /// Return the index in the ring-buffer of the slot that the next enqueued item would be
+    /// written to.
+    ///
+    /// This is meant for diagnostics only: the layout of the ring-buffer is an implementation
+    /// detail that may change in any release.
+    pub fn write_index(&self) -> usize {
+        self.write_to()
+    }
+
+    /// Return the total number of free slots, exactly like [`Queue::remaining`].
+    pub fn free(&self) -> usize {
+        N - self.amount
+    }
+
+    /// Return the number of free slots that directly follow the last item without wrapping around
+    /// the end of the ring-buffer, which is the length of the slice that [`Queue::expose_slots`]
+    /// would currently expose.
+    pub fn contiguous_free(&self) -> usize {
+        if self.amount == N {
+            0
+        } else if self.is_data_contiguous() {
+            N - self.write_to()
+        } else {
+            self.read - self.write_to()
+        }
+    }
+
+    /// Expose all free slots of the queue at once, as two slices of memory: first the slots
+    /// directly following the last item, then the slots that wrap around to the beginning of the
+    /// ring-buffer. The second slice is empty if the free slots are contiguous, and both are empty
+    /// if the queue is full.
+    ///
+    /// To enqueue items, fill the first slice and then the second one, and afterwards inform the
+    /// queue of the total number of items written via [`Queue::consider_enqueued`]. Its invariants
+    /// apply to the slots of both slices in that order.
+    pub fn expose_all_slots(&mut self) -> (&mut [MaybeUninit<T>], &mut [MaybeUninit<T>]) {
+        if self.amount == N {
+            (&mut [], &mut [])
+        } else {
+            let read = self.read;
+            let write_to = self.write_to();
+
+            if self.is_data_contiguous() {
+                let (wrapped, from_write) = self.data.split_at_mut(write_to);
+                (from_write, &mut wrapped[..read])
+            } else {
+                (&mut self.data[write_to..read], &mut [])
+            }
+        }
+    }
+
+    /// Return the two slices of memory that hold the items of the queue, in FIFO order. The
+    /// second slice is empty unless the items wrap around the end of the ring-buffer.
+    fn live_slices(&self) -> (&[MaybeUninit<T>], &[MaybeUninit<T>]) {
+        let head_len = core::cmp::min(self.amount, N - self.read);
+        let tail_len = self.amount - head_len;
+
+        (
+            &self.data[self.read..self.read + head_len],
+            &self.data[..tail_len],
+        )
+    }
+
     fn write_to(&self) -> usize {
-        (self.read + self.amount) % N
+        if N == 0 {
+            // There are no slots to write to, and no capacity to take the remainder by.
+            0
+        } else {
+            (self.read + self.amount) % N
+        }
+    }
+
+    /// Check the internal consistency of the indices of the queue, and panic if they are
+    /// inconsistent. Intended for tests and fuzzing, and only available in tests or with the
+    /// `validate` feature.
+    #[cfg(any(test, feature = "validate"))]
+    pub fn debug_validate(&self) {
+        let capacity = N;
+
+        assert!(
+            self.amount <= capacity,
+            "queue contains more items than its capacity"
+        );
+
+        if capacity == 0 {
+            assert_eq!(
+                self.read, 0,
+                "read index of a queue without capacity is not zero"
+            );
+        } else {
+            assert!(self.read < capacity, "read index out of bounds");
+            assert!(self.write_to() < capacity, "write index out of bounds");
+            assert_eq!(
+                (self.write_to() + capacity - self.amount) % capacity,
+                self.read,
+                "write index inconsistent with read index and length"
+            );
+        }
+    }
+}
+
+impl<T: Copy, const N: usize> Static<T, N> {
+    /// Create a queue that is completely filled with copies of the given item. Can be used in
+    /// `const` contexts.
+    pub const fn new_full(item: T) -> Self {
+        Static {
+            data: [MaybeUninit::new(item); N],
+            read: 0,
+            amount: N,
+        }
+    }
+
+    /// Create a queue that already contains the given items, with the first item of the slice
+    /// being the first one to be dequeued. Can be used in `const` contexts.
+    ///
+    /// Panic if the slice holds more than `N` items. In a `const` context, this is a compile-time error.
+    pub const fn from_slice(items: &[T]) -> Self {
+        assert!(
+            items.len() <= N,
+            "cannot create a Static queue from a slice that exceeds its capacity"
+        );
+
+        let mut queue = Self::new();
+        let mut i = 0;
+        while i < items.len() {
+            queue.data[i] = MaybeUninit::new(items[i]);
+            i += 1;
+        }
+        queue.amount = items.len();
+
+        queue
+    }
+
+    /// Attempt to enqueue an item, exactly like [`Queue::enqueue`], but usable in `const` contexts.
+    ///
+    /// Will return the item instead of enqueueing it if the queue is full at the time of calling.
+    pub const fn const_enqueue(&mut self, item: T) -> Option<T> {
+        if self.amount == N {
+            Some(item)
+        } else {
+            self.data[(self.read + self.amount) % N] = MaybeUninit::new(item);
+            self.amount += 1;
+
+            None
+        }
+    }
+
+    /// Attempt to dequeue the next item, exactly like [`Queue::dequeue`], but usable in `const`
+    /// contexts.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    pub const fn const_dequeue(&mut self) -> Option<T> {
+        if self.amount == 0 {
+            None
+        } else {
+            let previous_read = self.read;
+            // Advance the read index by 1 or reset to 0 if at capacity.
+            self.read = (self.read + 1) % N;
+            self.amount -= 1;
+
+            Some(unsafe { self.data[previous_read].assume_init() })
+        }
+    }
+
+    /// Return an iterator over the items of the queue, from front to back, without dequeueing them.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        let (head, tail) = self.as_slices();
+
+        head.iter().chain(tail).copied()
+    }
+
+    /// Return the item at the given position, counted from the front of the queue (so index
+    /// `0` holds the next item that would be dequeued), without dequeueing it.
+    ///
+    /// Will return `None` if `index >= self.len()`.
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index < self.amount {
+            Some(unsafe { self.data[(self.read + index) % N].assume_init() })
+        } else {
+            None
+        }
+    }
+
+    /// Enqueue an item, even if the queue is full. If it is, the oldest item is overwritten
+    /// and returned. Otherwise, this behaves exactly like [`Queue::enqueue`] and returns `None`.
+    ///
+    /// In a queue of capacity zero, the item itself is returned.
+    pub fn enqueue_overwrite(&mut self, item: T) -> Option<T> {
+        if N == 0 {
+            Some(item)
+        } else if self.amount == N {
+            // The write index coincides with the read index when the queue is full.
+            let evicted = unsafe { self.data[self.read].assume_init() };
+            self.data[self.read].write(item);
+            self.read = (self.read + 1) % N;
+
+            Some(evicted)
+        } else {
+            self.enqueue(item)
+        }
+    }
+
+    /// Return an iterator that dequeues the items of the queue, from front to back. When the
+    /// iterator is dropped, all items it has not yielded are removed from the queue as well, so
+    /// that the queue is empty afterwards (but keeps its capacity).
+    pub fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        Drain { queue: self }
+    }
+
+    /// Enqueue items from an iterator until the iterator is exhausted or the queue is full, and
+    /// return how many items were enqueued. Items are only taken from the iterator if they can
+    /// be enqueued.
+    pub fn extend_counted<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        let mut count = 0;
+
+        for item in iter.into_iter().take(self.remaining()) {
+            self.data[self.write_to()].write(item);
+            self.amount += 1;
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Retain only the items for which the predicate returns `true`, removing all others from
+    /// the queue. The retained items keep their order. Mirrors [`Vec::retain`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.retain).
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut kept = 0;
+
+        // Compact the retained items towards the front of the queue, which never overwrites an
+        // item that has not been visited yet.
+        for i in 0..self.amount {
+            let item = unsafe { self.data[(self.read + i) % N].assume_init() };
+            if f(&item) {
+                self.data[(self.read + kept) % N].write(item);
+                kept += 1;
+            }
+        }
+
+        self.amount = kept;
+    }
+
+    /// Shorten the queue to the given length by removing the most recently enqueued items, so
+    /// that the first `len` items remain. Mirrors [`Vec::truncate`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.truncate).
+    ///
+    /// Has no effect if `len >= self.len()`.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.amount {
+            self.amount = len;
+        }
+    }
+
+    /// Rotate the queue `n` places to the left, so that the first `n` items move to the back of
+    /// the queue, in order. Mirrors [`VecDeque::rotate_left`](https://doc.rust-lang.org/std/collections/struct.VecDeque.html#method.rotate_left).
+    ///
+    /// Takes `O(min(n, self.len() - n))` time, or constant time if the queue is full.
+    ///
+    /// Panic if `n > self.len()`.
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(
+            n <= self.amount,
+            "cannot rotate a queue by more places than it has items"
+        );
+
+        if self.amount == N {
+            // The slots of a full queue all hold items, so only the read index needs to move.
+            if n > 0 {
+                self.read = (self.read + n) % N;
+            }
+        } else if n <= self.amount - n {
+            for _ in 0..n {
+                self.move_front_to_back();
+            }
+        } else {
+            for _ in 0..self.amount - n {
+                self.move_back_to_front();
+            }
+        }
+    }
+
+    /// Rotate the queue `n` places to the right, so that the last `n` items move to the front of
+    /// the queue, in order. Mirrors [`VecDeque::rotate_right`](https://doc.rust-lang.org/std/collections/struct.VecDeque.html#method.rotate_right).
+    ///
+    /// Takes `O(min(n, self.len() - n))` time, or constant time if the queue is full.
+    ///
+    /// Panic if `n > self.len()`.
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(
+            n <= self.amount,
+            "cannot rotate a queue by more places than it has items"
+        );
+
+        self.rotate_left(self.amount - n);
+    }
+
+    /// Move the first item of a queue that is neither empty nor full to its back.
+    fn move_front_to_back(&mut self) {
+        let front = self.read;
+        let item = unsafe { self.data[front].assume_init() };
+        self.data[self.write_to()].write(item);
+        self.read = (self.read + 1) % N;
+    }
+
+    /// Move the last item of a queue that is neither empty nor full to its front.
+    fn move_back_to_front(&mut self) {
+        let back = (self.read + self.amount - 1) % N;
+        let item = unsafe { self.data[back].assume_init() };
+        self.read = (self.read + N - 1) % N;
+        self.data[self.read].write(item);
+    }
+
+    /// Move as many items as fit from the front of another queue to the back of this one, in
+    /// order, and return how many items were moved. Items that do not fit remain in the other
+    /// queue. Copies the items directly between the two ring-buffers.
+    pub fn append(&mut self, other: &mut Self) -> usize {
+        let (head, tail) = other.as_slices();
+
+        let head_amount = self.bulk_enqueue(head);
+        let tail_amount = if head_amount == head.len() {
+            self.bulk_enqueue(tail)
+        } else {
+            0
+        };
+
+        other.consider_dequeued(head_amount + tail_amount);
+        head_amount + tail_amount
+    }
+
+    /// Remove up to `n` items from the front of the queue without reading them, and return how
+    /// many items were removed. Removes all items if `n >= self.len()`.
+    pub fn discard(&mut self, n: usize) -> usize {
+        let amount = min(n, self.amount);
+        self.consider_dequeued(amount);
+
+        amount
     }
 }
 
@@ -70,6 +599,11 @@ impl<T: Copy, const N: usize> Queue for Static<T, N> {
         self.amount
     }
 
+    /// Return the capacity of this queue, i.e., `N`.
+    fn capacity(&self) -> usize {
+        N
+    }
+
     /// Attempt to enqueue the next item.
     ///
     /// Will return the item if the queue is full at the time of calling.
@@ -84,6 +618,12 @@ impl<T: Copy, const N: usize> Queue for Static<T, N> {
         }
     }
 
+    /// Enqueue an item, overwriting and returning the oldest item if the queue is full, exactly
+    /// like [`Static::enqueue_overwrite`].
+    fn enqueue_evicting(&mut self, item: T) -> Option<T> {
+        self.enqueue_overwrite(item)
+    }
+
     /// Expose a non-empty slice of memory for the client code to fill with items that should
     /// be enqueued.
     ///
@@ -111,9 +651,32 @@ impl<T: Copy, const N: usize> Queue for Static<T, N> {
     /// originally uninitialized. Violating the invariants will cause the queue to read undefined
     /// memory, which triggers undefined behavior.
     unsafe fn consider_enqueued(&mut self, amount: usize) {
+        debug_assert!(
+            self.amount + amount <= N,
+            "cannot consider more items enqueued than the queue has slots"
+        );
+
         self.amount += amount;
     }
 
+    /// Enqueue a non-zero number of items by reading them from a given buffer and returning how
+    /// many items were enqueued. Fills the free slots both before and after the end of the
+    /// ring-buffer, so that as many items as fit are enqueued in a single call.
+    ///
+    /// Will return `0` if the queue is full at the time of calling.
+    fn bulk_enqueue(&mut self, buffer: &[T]) -> usize {
+        let (first, second) = self.expose_all_slots();
+
+        let first_amount = min(first.len(), buffer.len());
+        first[..first_amount].write_copy_of_slice(&buffer[..first_amount]);
+        let second_amount = min(second.len(), buffer.len() - first_amount);
+        second[..second_amount]
+            .write_copy_of_slice(&buffer[first_amount..first_amount + second_amount]);
+
+        self.amount += first_amount + second_amount;
+        first_amount + second_amount
+    }
+
     /// Attempt to dequeue the next item.
     ///
     /// Will return `None` if the queue is empty at the time of calling.
@@ -121,6 +684,8 @@ impl<T: Copy, const N: usize> Queue for Static<T, N> {
         if self.amount == 0 {
             None
         } else {
+            debug_assert!(self.read < N, "read index out of bounds");
+
             let previous_read = self.read;
             // Advance the read index by 1 or reset to 0 if at capacity.
             self.read = (self.read + 1) % N;
@@ -130,6 +695,28 @@ impl<T: Copy, const N: usize> Queue for Static<T, N> {
         }
     }
 
+    /// Return the next item that would be dequeued, without removing it from the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn peek(&self) -> Option<T> {
+        if self.amount == 0 {
+            None
+        } else {
+            Some(unsafe { self.data[self.read].assume_init() })
+        }
+    }
+
+    /// Return the most recently enqueued item, without removing it from the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn last(&self) -> Option<T> {
+        if self.amount == 0 {
+            None
+        } else {
+            Some(unsafe { self.data[(self.read + self.amount - 1) % N].assume_init() })
+        }
+    }
+
     /// Expose a non-empty slice of items to be dequeued.
     ///
     /// Will return `None` if the queue is empty at the time of calling.
@@ -137,7 +724,7 @@ impl<T: Copy, const N: usize> Queue for Static<T, N> {
         if self.amount == 0 {
             None
         } else {
-            Some(unsafe { MaybeUninit::slice_assume_init_ref(self.readable_slice()) })
+            Some(unsafe { self.readable_slice().assume_init_ref() })
         }
     }
 
@@ -148,73 +735,256 @@ impl<T: Copy, const N: usize> Queue for Static<T, N> {
     /// Callers must not mark items as dequeued that had not previously been exposed by
     /// `expose_items`.
     fn consider_dequeued(&mut self, amount: usize) {
-        self.read = (self.read + amount) % N;
-        self.amount -= amount;
-    }
-}
+        debug_assert!(
+            amount <= self.amount,
+            "cannot consider more items dequeued than the queue contains"
+        );
 
-impl<T: fmt::Debug, const N: usize> fmt::Debug for Static<T, N> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Static")
-            .field("len", &self.amount)
-            .field_with("data", |f| {
-                let mut list = f.debug_list();
-
-                if self.is_data_contiguous() {
-                    for item in unsafe {
-                        MaybeUninit::slice_assume_init_ref(&self.data[self.read..self.write_to()])
-                    } {
-                        list.entry(item);
-                    }
-                } else {
-                    for item in
-                        unsafe { MaybeUninit::slice_assume_init_ref(&self.data[self.read..]) }
-                    {
-                        list.entry(item);
-                    }
-
-                    for item in unsafe {
-                        MaybeUninit::slice_assume_init_ref(
-                            &self.data[0..(self.amount - self.data[self.read..].len())],
-                        )
-                    } {
-                        list.entry(item);
-                    }
-                }
-
-                list.finish()
-            })
-            .finish()
+        if amount > 0 {
+            self.read = (self.read + amount) % N;
+            self.amount -= amount;
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use alloc::format;
+    /// Dequeue a non-zero number of items by writing them into a given buffer and returning how
+    /// many items were dequeued. Reads the items both before and after the end of the
+    /// ring-buffer, so that as many items as fit into the buffer are dequeued in a single call.
+    ///
+    /// Will return `0` if the queue is empty at the time of calling.
+    fn bulk_dequeue(&mut self, buffer: &mut [T]) -> usize {
+        let (head, tail) = self.as_slices();
 
-    use super::*;
+        let head_amount = min(head.len(), buffer.len());
+        buffer[..head_amount].copy_from_slice(&head[..head_amount]);
+        let tail_amount = min(tail.len(), buffer.len() - head_amount);
+        buffer[head_amount..head_amount + tail_amount].copy_from_slice(&tail[..tail_amount]);
 
-    #[test]
-    fn enqueues_and_dequeues_with_correct_amount() {
-        let mut queue: Static<u8, 4> = Static::new();
+        self.consider_dequeued(head_amount + tail_amount);
+        head_amount + tail_amount
+    }
 
-        assert_eq!(queue.enqueue(7), None);
-        assert_eq!(queue.enqueue(21), None);
-        assert_eq!(queue.enqueue(196), None);
-        assert_eq!(queue.len(), 3);
+    /// Dequeue a non-zero number of items by writing them into a given buffer of possible
+    /// uninitialised memory and returning how many items were dequeued. Reads the items both
+    /// before and after the end of the ring-buffer, so that as many items as fit into the buffer
+    /// are dequeued in a single call.
+    ///
+    /// Will return `0` if the queue is empty at the time of calling.
+    fn bulk_dequeue_uninit(&mut self, buffer: &mut [MaybeUninit<T>]) -> usize {
+        let (head, tail) = self.as_slices();
 
-        assert_eq!(queue.enqueue(233), None);
-        assert_eq!(queue.len(), 4);
+        let head_amount = min(head.len(), buffer.len());
+        buffer[..head_amount].write_copy_of_slice(&head[..head_amount]);
+        let tail_amount = min(tail.len(), buffer.len() - head_amount);
+        buffer[head_amount..head_amount + tail_amount].write_copy_of_slice(&tail[..tail_amount]);
 
-        // Queue should be first-in, first-out.
-        assert_eq!(queue.dequeue(), Some(7));
-        assert_eq!(queue.len(), 3);
+        self.consider_dequeued(head_amount + tail_amount);
+        head_amount + tail_amount
     }
+}
 
-    #[test]
-    fn bulk_enqueues_and_dequeues_with_correct_amount() {
-        let mut queue: Static<u8, 4> = Static::new();
-        let mut buf: [MaybeUninit<u8>; 4] = MaybeUninit::uninit_array();
+/// The iterator returned by [`Static::drain`].
+struct Drain<'a, T, const N: usize> {
+    queue: &'a mut Static<T, N>,
+}
+
+impl<T: Copy, const N: usize> Iterator for Drain<'_, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.queue.dequeue()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.queue.amount, Some(self.queue.amount))
+    }
+}
+
+impl<T, const N: usize> Drop for Drain<'_, T, N> {
+    fn drop(&mut self) {
+        // Items are `Copy`, so there is nothing to drop.
+        self.queue.read = 0;
+        self.queue.amount = 0;
+    }
+}
+
+impl<T: Copy, const N: usize> Deque for Static<T, N> {
+    /// Attempt to enqueue an item at the front of the queue, so that it is the next item to be dequeued.
+    ///
+    /// Will return the item if the queue is full at the time of calling.
+    fn push_front(&mut self, item: T) -> Option<T> {
+        if self.amount == N {
+            Some(item)
+        } else {
+            // Move the read index back by 1 or wrap around to the last slot.
+            self.read = (self.read + N - 1) % N;
+            self.data[self.read].write(item);
+            self.amount += 1;
+
+            None
+        }
+    }
+
+    /// Attempt to remove the most recently enqueued item from the back of the queue.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn pop_back(&mut self) -> Option<T> {
+        if self.amount == 0 {
+            None
+        } else {
+            self.amount -= 1;
+
+            Some(unsafe { self.data[self.write_to()].assume_init() })
+        }
+    }
+}
+
+impl<T: Copy, const N: usize> Clone for Static<T, N> {
+    /// Create a queue holding the same items. Only the items currently in the queue are copied,
+    /// into the start of the new queue's buffer.
+    fn clone(&self) -> Self {
+        let mut queue = Self::new();
+
+        let (head, tail) = self.live_slices();
+        queue.data[..head.len()].copy_from_slice(head);
+        queue.data[head.len()..self.amount].copy_from_slice(tail);
+        queue.amount = self.amount;
+
+        queue
+    }
+
+    /// Replace the items of this queue with copies of the items of `source`, in place. Afterwards,
+    /// the items are stored contiguously, starting at the beginning of the buffer.
+    fn clone_from(&mut self, source: &Self) {
+        let (head, tail) = source.live_slices();
+        self.data[..head.len()].copy_from_slice(head);
+        self.data[head.len()..source.amount].copy_from_slice(tail);
+        self.read = 0;
+        self.amount = source.amount;
+    }
+}
+
+/// Collect up to `N` items into a queue. If the iterator yields more than `N` items, the
+/// remaining items are not enqueued (and not even taken from the iterator).
+impl<T, const N: usize> FromIterator<T> for Static<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = Self::new();
+
+        for (slot, item) in queue.data.iter_mut().zip(iter) {
+            slot.write(item);
+            queue.amount += 1;
+        }
+
+        queue
+    }
+}
+
+/// Move the items of a double-ended queue into a queue, preserving FIFO order. Fail and return
+/// the double-ended queue unchanged if it holds more than `N` items.
+#[cfg(feature = "std")]
+impl<T, const N: usize> TryFrom<std::collections::VecDeque<T>> for Static<T, N> {
+    type Error = std::collections::VecDeque<T>;
+
+    fn try_from(deque: std::collections::VecDeque<T>) -> Result<Self, Self::Error> {
+        if deque.len() > N {
+            Err(deque)
+        } else {
+            Ok(deque.into_iter().collect())
+        }
+    }
+}
+
+/// Enqueue items until the queue is full. Once the queue is full, all further items are
+/// silently discarded. Use [`Static::extend_counted`] to learn how many items were enqueued.
+impl<T: Copy, const N: usize> Extend<T> for Static<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend_counted(iter);
+    }
+}
+
+/// Queues are equal if they contain equal items in the same order, regardless of their capacities.
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<Static<T, M>> for Static<T, N> {
+    fn eq(&self, other: &Static<T, M>) -> bool {
+        let (head, tail) = self.as_slices();
+        let (other_head, other_tail) = other.as_slices();
+
+        self.amount == other.amount
+            && head
+                .iter()
+                .chain(tail)
+                .eq(other_head.iter().chain(other_tail))
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for Static<T, N> {}
+
+/// Hashes the number of items, followed by the items in FIFO order, consistent with [`PartialEq`].
+impl<T: Hash, const N: usize> Hash for Static<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let (head, tail) = self.as_slices();
+
+        self.amount.hash(state);
+        for item in head.iter().chain(tail) {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Static<T, N> {
+    type Output = T;
+
+    /// Return a reference to the item at the given position, counted from the front of the queue.
+    ///
+    /// Panics if `index >= self.len()`.
+    fn index(&self, index: usize) -> &T {
+        assert!(
+            index < self.amount,
+            "index out of bounds: the len is {} but the index is {}",
+            self.amount,
+            index
+        );
+
+        unsafe { self.data[(self.read + index) % N].assume_init_ref() }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for Static<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (head, tail) = self.as_slices();
+
+        f.debug_struct("Static")
+            .field("len", &self.amount)
+            .field("data", &DebugItems(head, tail))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn enqueues_and_dequeues_with_correct_amount() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.enqueue(21), None);
+        assert_eq!(queue.enqueue(196), None);
+        assert_eq!(queue.len(), 3);
+
+        assert_eq!(queue.enqueue(233), None);
+        assert_eq!(queue.len(), 4);
+
+        // Queue should be first-in, first-out.
+        assert_eq!(queue.dequeue(), Some(7));
+        assert_eq!(queue.len(), 3);
+    }
+
+    #[test]
+    fn bulk_enqueues_and_dequeues_with_correct_amount() {
+        let mut queue: Static<u8, 4> = Static::new();
+        let mut buf: [MaybeUninit<u8>; 4] = [MaybeUninit::uninit(); 4];
 
         let enqueue_amount = queue.bulk_enqueue(b"ufo");
         let dequeue_amount = queue.bulk_dequeue_uninit(&mut buf);
@@ -251,14 +1021,14 @@ mod tests {
         // Copy data to two of the available slots and call `consider_queued`.
         let data = b"tofu";
         let slots = queue.expose_slots().unwrap();
-        MaybeUninit::copy_from_slice(&mut slots[0..2], &data[0..2]);
+        slots[0..2].write_copy_of_slice(&data[0..2]);
         unsafe {
             queue.consider_enqueued(2);
         }
 
         // Copy data to two of the available slots and call `consider_queued`.
         let slots = queue.expose_slots().unwrap();
-        MaybeUninit::copy_from_slice(&mut slots[0..2], &data[0..2]);
+        slots[0..2].write_copy_of_slice(&data[0..2]);
         unsafe {
             queue.consider_enqueued(2);
         }
@@ -283,36 +1053,1397 @@ mod tests {
     }
 
     #[test]
-    fn test_debug_impl() {
-        let mut queue: Static<u8, 4> = Static::new();
+    fn returns_none_on_peek_when_queue_is_empty() {
+        let mut queue: Static<u8, 1> = Static::new();
+
+        assert_eq!(queue.peek(), None);
+
+        // Enqueue and then dequeue an item.
+        let _ = queue.enqueue(7);
+        let _ = queue.dequeue();
+
+        // The queue is now empty again.
+        assert_eq!(queue.peek(), None);
+    }
+
+    #[test]
+    fn peek_returns_the_item_that_is_dequeued_next() {
+        let mut queue: Static<u8, 3> = Static::new();
 
         assert_eq!(queue.enqueue(7), None);
         assert_eq!(queue.enqueue(21), None);
-        assert_eq!(queue.enqueue(196), None);
-        assert_eq!(
-            format!("{:?}", queue),
-            "Static { len: 3, data: [7, 21, 196] }"
-        );
 
-        assert_eq!(queue.dequeue(), Some(7));
-        assert_eq!(format!("{:?}", queue), "Static { len: 2, data: [21, 196] }");
+        // Peeking does not remove the item.
+        assert_eq!(queue.peek(), Some(7));
+        assert_eq!(queue.peek(), Some(7));
+        assert_eq!(queue.len(), 2);
 
+        assert_eq!(queue.dequeue(), Some(7));
+        assert_eq!(queue.peek(), Some(21));
         assert_eq!(queue.dequeue(), Some(21));
-        assert_eq!(format!("{:?}", queue), "Static { len: 1, data: [196] }");
+    }
 
-        assert_eq!(queue.enqueue(33), None);
-        assert_eq!(format!("{:?}", queue), "Static { len: 2, data: [196, 33] }");
+    #[test]
+    fn peeks_correctly_after_wraparound() {
+        let mut queue: Static<u8, 3> = Static::new();
 
-        assert_eq!(queue.enqueue(17), None);
-        assert_eq!(
-            format!("{:?}", queue),
-            "Static { len: 3, data: [196, 33, 17] }"
-        );
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
 
-        assert_eq!(queue.enqueue(200), None);
-        assert_eq!(
-            format!("{:?}", queue),
-            "Static { len: 4, data: [196, 33, 17, 200] }"
-        );
+        // This item is stored at the start of the ring-buffer.
+        assert_eq!(queue.enqueue(4), None);
+        assert_eq!(queue.peek(), Some(3));
+        assert_eq!(queue.dequeue(), Some(3));
+
+        // The read index has wrapped around.
+        assert_eq!(queue.peek(), Some(4));
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.peek(), None);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "cannot consider more items dequeued than the queue contains")]
+    fn panics_on_considering_too_many_items_dequeued() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        queue.consider_dequeued(4);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "cannot consider more items enqueued than the queue has slots")]
+    fn panics_on_considering_too_many_items_enqueued() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        unsafe {
+            queue.consider_enqueued(2);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "read index out of bounds")]
+    fn panics_on_dequeue_with_corrupted_read_index() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        assert_eq!(queue.enqueue(7), None);
+        queue.read = 4;
+        let _ = queue.dequeue();
+    }
+
+    #[test]
+    fn reports_capacity_and_remaining() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.capacity(), 4);
+        assert_eq!(queue.remaining(), 4);
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.capacity(), 4);
+        assert_eq!(queue.remaining(), 1);
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.remaining(), 0);
+
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        assert_eq!(queue.remaining(), 1);
+    }
+
+    #[test]
+    fn from_array_creates_prefilled_queue() {
+        let mut queue: Static<u8, 5> = Static::from_array([1, 2, 3]);
+        assert_eq!(queue.len(), 3);
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn from_array_can_fill_queue_completely() {
+        let mut queue: Static<u8, 3> = Static::from_array([1, 2, 3]);
+
+        assert_eq!(queue.enqueue(4), Some(4));
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.enqueue(4), None);
+    }
+
+    #[test]
+    fn iterates_over_items_without_dequeueing() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.iter().next(), None);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert!(queue.iter().eq([1, 2, 3]));
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.dequeue(), Some(1));
+    }
+
+    #[test]
+    fn iterates_over_wrapped_items() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        // These items are stored at the start of the ring-buffer.
+        assert_eq!(queue.enqueue(5), None);
+        assert_eq!(queue.enqueue(6), None);
+
+        assert!(queue.iter().eq([4, 5, 6]));
+    }
+
+    #[test]
+    fn as_slices_of_contiguous_items() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.as_slices(), (&[][..], &[][..]));
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.as_slices(), (&[2, 3][..], &[][..]));
+    }
+
+    #[test]
+    fn as_slices_of_wrapped_items() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.enqueue(5), None);
+        assert_eq!(queue.as_slices(), (&[3, 4][..], &[5][..]));
+
+        // Completely full, with the read index in the middle of the ring-buffer.
+        assert_eq!(queue.enqueue(6), None);
+        assert_eq!(queue.as_slices(), (&[3, 4][..], &[5, 6][..]));
+    }
+
+    #[test]
+    fn enqueue_overwrite_keeps_newest_items() {
+        let mut queue: Static<u8, 3> = Static::new();
+
+        assert_eq!(queue.enqueue_overwrite(1), None);
+        assert_eq!(queue.enqueue_overwrite(2), None);
+        assert_eq!(queue.enqueue_overwrite(3), None);
+
+        assert_eq!(queue.enqueue_overwrite(4), Some(1));
+        assert_eq!(queue.enqueue_overwrite(5), Some(2));
+        assert_eq!(queue.len(), 3);
+        assert!(queue.iter().eq([3, 4, 5]));
+
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.enqueue_overwrite(6), None);
+        assert_eq!(queue.enqueue_overwrite(7), Some(4));
+
+        assert_eq!(queue.dequeue(), Some(5));
+        assert_eq!(queue.dequeue(), Some(6));
+        assert_eq!(queue.dequeue(), Some(7));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn clones_are_independent() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(5), None);
+        assert_eq!(queue.enqueue(6), None);
+
+        let mut clone = queue.clone();
+        assert_eq!(clone.capacity(), 4);
+        assert_eq!(clone.len(), 4);
+
+        for item in 3..=6 {
+            assert_eq!(queue.dequeue(), Some(item));
+        }
+        assert_eq!(queue.dequeue(), None);
+
+        assert_eq!(clone.enqueue(7), Some(7));
+        for item in 3..=6 {
+            assert_eq!(clone.dequeue(), Some(item));
+        }
+        assert_eq!(clone.dequeue(), None);
+    }
+
+    #[test]
+    fn compares_contents_regardless_of_layout() {
+        let mut wrapped: Static<u8, 4> = Static::new();
+        assert_eq!(wrapped.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(wrapped.dequeue(), Some(1));
+        assert_eq!(wrapped.dequeue(), Some(2));
+        assert_eq!(wrapped.enqueue(5), None);
+
+        let contiguous: Static<u8, 3> = Static::from_array([3, 4, 5]);
+        assert_eq!(wrapped, contiguous);
+
+        let mut other: Static<u8, 3> = Static::from_array([3, 4, 6]);
+        assert_ne!(wrapped, other);
+        assert_eq!(other.dequeue(), Some(3));
+        assert_ne!(other, Static::<u8, 3>::from_array([4, 6, 7]));
+        assert_eq!(Static::<u8, 2>::new(), Static::<u8, 4>::new());
+    }
+
+    #[test]
+    fn collects_exactly_fitting_items() {
+        let mut queue: Static<u8, 3> = (1..=3).collect();
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.enqueue(4), Some(4));
+        assert!(queue.iter().eq([1, 2, 3]));
+    }
+
+    #[test]
+    fn collects_fewer_items_than_capacity() {
+        let mut queue: Static<u8, 5> = (1..=3).collect();
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.enqueue(4), None);
+        assert!(queue.iter().eq([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn collects_at_most_capacity_many_items() {
+        let mut items = 1..=5;
+        let queue: Static<u8, 3> = items.by_ref().collect();
+        assert!(queue.iter().eq([1, 2, 3]));
+
+        // Surplus items are left in the iterator.
+        assert!(items.eq([4, 5]));
+    }
+
+    #[test]
+    fn extends_until_full() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        queue.extend([1, 2]);
+        assert!(queue.iter().eq([1, 2]));
+
+        // Items that do not fit are discarded.
+        queue.extend([3, 4, 5, 6]);
+        assert_eq!(queue.len(), 4);
+        assert!(queue.iter().eq([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn extend_counted_reports_enqueued_items() {
+        let mut queue: Static<u8, 4> = Static::new();
+        let mut items = 1..=6;
+
+        assert_eq!(queue.extend_counted(items.by_ref().take(2)), 2);
+        assert_eq!(queue.extend_counted(items.by_ref()), 2);
+        assert!(queue.iter().eq([1, 2, 3, 4]));
+
+        // Items that could not be enqueued are left in the iterator.
+        assert!(items.eq([5, 6]));
+        assert_eq!(queue.extend_counted([7]), 0);
+    }
+
+    #[test]
+    fn last_tracks_newest_item() {
+        let mut queue: Static<u8, 3> = Static::new();
+        assert_eq!(queue.last(), None);
+
+        assert_eq!(queue.enqueue(1), None);
+        assert_eq!(queue.last(), Some(1));
+        assert_eq!(queue.enqueue(2), None);
+        assert_eq!(queue.enqueue(3), None);
+        assert_eq!(queue.last(), Some(3));
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.last(), Some(3));
+
+        // This item is stored at the start of the ring-buffer.
+        assert_eq!(queue.enqueue(4), None);
+        assert_eq!(queue.last(), Some(4));
+
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.last(), Some(4));
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.last(), None);
+    }
+
+    #[test]
+    fn mixes_front_and_back_operations() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.pop_back(), None);
+
+        assert_eq!(queue.enqueue(2), None);
+        // Wraps around to the end of the ring-buffer.
+        assert_eq!(queue.push_front(1), None);
+        assert_eq!(queue.enqueue(3), None);
+        assert_eq!(queue.push_front(0), None);
+        assert!(queue.iter().eq([0, 1, 2, 3]));
+
+        assert_eq!(queue.push_front(7), Some(7));
+        assert_eq!(queue.enqueue(7), Some(7));
+
+        assert_eq!(queue.pop_back(), Some(3));
+        assert_eq!(queue.dequeue(), Some(0));
+        assert_eq!(queue.pop_back(), Some(2));
+        assert_eq!(queue.push_front(5), None);
+        assert_eq!(queue.enqueue(6), None);
+        assert!(queue.iter().eq([5, 1, 6]));
+
+        assert_eq!(queue.pop_back(), Some(6));
+        assert_eq!(queue.pop_back(), Some(1));
+        assert_eq!(queue.pop_back(), Some(5));
+        assert_eq!(queue.pop_back(), None);
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn make_contiguous_rearranges_wrapped_items() {
+        let mut queue: Static<u8, 5> = Static::new();
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4, 5]), 5);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(6), None);
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.as_slices(), (&[4, 5][..], &[6, 7][..]));
+
+        assert_eq!(queue.make_contiguous(), &[4, 5, 6, 7]);
+        assert_eq!(queue.as_slices(), (&[4, 5, 6, 7][..], &[][..]));
+        assert_eq!(queue.expose_items(), Some(&[4, 5, 6, 7][..]));
+
+        // The queue keeps working normally afterwards.
+        assert_eq!(queue.enqueue(8), None);
+        assert_eq!(queue.enqueue(9), Some(9));
+        for item in 4..=8 {
+            assert_eq!(queue.dequeue(), Some(item));
+        }
+        assert_eq!(queue.make_contiguous(), &[]);
+    }
+
+    #[test]
+    fn partial_drain_empties_queue() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.enqueue(5), None);
+
+        let mut drain = queue.drain();
+        assert_eq!(drain.size_hint(), (4, Some(4)));
+        assert_eq!(drain.next(), Some(2));
+        assert_eq!(drain.next(), Some(3));
+        drop(drain);
+
+        assert!(queue.is_empty());
+        assert_eq!(queue.capacity(), 4);
+        assert_eq!(queue.bulk_enqueue(&[6, 7, 8, 9]), 4);
+        assert!(queue.iter().eq([6, 7, 8, 9]));
+    }
+
+    #[test]
+    fn full_drain_yields_all_items() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert!(queue.drain().eq([1, 2, 3]));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn try_enqueue_reports_full_queue() {
+        let mut queue: Static<u8, 1> = Static::new();
+
+        assert_eq!(queue.try_enqueue(7), Ok(()));
+
+        match queue.try_enqueue(8) {
+            Ok(()) => panic!("enqueued into a full queue"),
+            Err(err) => {
+                assert_eq!(format!("{}", err), "cannot enqueue into a full queue");
+                assert_eq!(err.into_inner(), 8);
+            }
+        }
+
+        assert_eq!(queue.dequeue(), Some(7));
+        assert_eq!(queue.try_enqueue(8), Ok(()));
+    }
+
+    #[test]
+    fn new_full_in_const_context() {
+        const FULL: Static<u8, 3> = Static::new_full(7);
+
+        let mut queue = FULL;
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.enqueue(8), Some(8));
+        assert!(queue.drain().eq([7, 7, 7]));
+    }
+
+    #[test]
+    fn from_slice_in_const_context() {
+        const PRELOADED: Static<u8, 5> = Static::from_slice(b"ufo");
+
+        let mut queue = PRELOADED;
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.bulk_enqueue(b"tofu"), 2);
+        assert!(queue.iter().eq(*b"ufoto"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "cannot create a Static queue from a slice that exceeds its capacity"
+    )]
+    fn from_slice_panics_when_exceeding_capacity() {
+        let _queue: Static<u8, 2> = Static::from_slice(b"ufo");
+    }
+
+    #[test]
+    fn gets_items_by_index_across_the_wrap_boundary() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.get(0), None);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(5), None);
+        assert_eq!(queue.enqueue(6), None);
+
+        assert_eq!(queue.get(0), Some(3));
+        assert_eq!(queue.get(1), Some(4));
+        assert_eq!(queue.get(2), Some(5));
+        assert_eq!(queue.get(3), Some(6));
+        assert_eq!(queue.get(4), None);
+
+        assert_eq!(queue[0], 3);
+        assert_eq!(queue[3], 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn index_panics_when_out_of_range() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.enqueue(1), None);
+
+        let _ = queue[1];
+    }
+
+    #[test]
+    fn retains_items_in_order_in_wrapped_queue() {
+        let mut queue: Static<u8, 6> = Static::new();
+        assert_eq!(queue.bulk_enqueue(&[0, 0, 0, 1, 2, 3]), 6);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[4, 5, 6]), 3);
+
+        queue.retain(|item| item % 2 == 0);
+        assert_eq!(queue.len(), 3);
+        assert!(queue.iter().eq([2, 4, 6]));
+
+        // The freed slots can be used again.
+        assert_eq!(queue.bulk_enqueue(&[7, 8, 9]), 3);
+        assert!(queue.iter().eq([2, 4, 6, 7, 8, 9]));
+
+        queue.retain(|_| false);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn is_full_flips_across_enqueue_and_dequeue() {
+        let mut queue: Static<u8, 2> = Static::new();
+        assert!(!queue.is_full());
+
+        assert_eq!(queue.enqueue(1), None);
+        assert!(!queue.is_full());
+        assert_eq!(queue.enqueue(2), None);
+        assert!(queue.is_full());
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert!(!queue.is_full());
+        assert_eq!(queue.enqueue(3), None);
+        assert!(queue.is_full());
+    }
+
+    #[test]
+    fn bulk_enqueue_remainder_returns_unaccepted_tail() {
+        let mut queue: Static<u8, 6> = Static::new();
+        assert_eq!(queue.bulk_enqueue(&[0, 0, 0, 0]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 2]), 2);
+        // Leaves room for 4 items, which wrap around the end of the ring-buffer.
+
+        let items = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        assert_eq!(queue.bulk_enqueue_remainder(&items), &items[4..]);
+        assert!(queue.iter().eq([0, 0, 1, 2, 3, 4]));
+
+        assert_eq!(queue.bulk_enqueue_remainder(&items), &items[..]);
+        assert_eq!(queue.dequeue(), Some(0));
+        assert_eq!(queue.bulk_enqueue_remainder(&items[..1]), &[]);
+    }
+
+    #[test]
+    fn fills_all_slots_in_one_pass() {
+        let mut queue: Static<u8, 6> = Static::new();
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 2]), 2);
+
+        let (first, second) = queue.expose_all_slots();
+        assert_eq!((first.len(), second.len()), (2, 2));
+        first.write_copy_of_slice(&[5, 6]);
+        second.write_copy_of_slice(&[7, 8]);
+        unsafe {
+            queue.consider_enqueued(4);
+        }
+        assert!(queue.iter().eq([3, 4, 5, 6, 7, 8]));
+
+        let (first, second) = queue.expose_all_slots();
+        assert_eq!((first.len(), second.len()), (0, 0));
+
+        // Free slots between the last item and the front of the queue are contiguous.
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+        let (first, second) = queue.expose_all_slots();
+        assert_eq!((first.len(), second.len()), (3, 0));
+        first[0].write(9);
+        unsafe {
+            queue.consider_enqueued(1);
+        }
+        assert!(queue.iter().eq([6, 7, 8, 9]));
+    }
+
+    #[test]
+    fn capacity_zero_rejects_everything() {
+        use crate::QueueFull;
+
+        let mut queue: Static<u8, 0> = Static::new();
+        assert_eq!(queue.capacity(), 0);
+        assert!(queue.is_empty());
+        assert!(queue.is_full());
+        assert_eq!(queue.remaining(), 0);
+
+        assert_eq!(queue.enqueue(1), Some(1));
+        assert_eq!(queue.try_enqueue(1), Err(QueueFull(1)));
+        assert_eq!(queue.enqueue_overwrite(1), Some(1));
+        assert_eq!(queue.push_front(1), Some(1));
+        assert!(queue.expose_slots().is_none());
+        assert_eq!(queue.expose_all_slots().0.len(), 0);
+        unsafe {
+            queue.consider_enqueued(0);
+        }
+        assert_eq!(queue.bulk_enqueue(&[1, 2]), 0);
+        assert_eq!(queue.bulk_enqueue_remainder(&[1, 2]), &[1, 2]);
+        assert_eq!(queue.extend_counted([1, 2]), 0);
+
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.pop_back(), None);
+        assert_eq!(queue.peek(), None);
+        assert_eq!(queue.last(), None);
+        assert_eq!(queue.get(0), None);
+        assert!(queue.expose_items().is_none());
+        queue.consider_dequeued(0);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 2]), 0);
+        assert_eq!(queue.drain().next(), None);
+        queue.retain(|_| true);
+        assert_eq!(queue.make_contiguous(), &[]);
+        assert_eq!(queue.iter().next(), None);
+
+        let mut other: Static<u8, 2> = Static::new();
+        assert_eq!(other.enqueue(1), None);
+        assert_eq!(queue.bulk_enqueue_from(&mut other), 0);
+        assert_eq!(other.bulk_enqueue_from(&mut queue), 0);
+
+        assert_eq!(queue.clone(), Static::<u8, 0>::from_slice(&[]));
+        assert_eq!(Static::<u8, 0>::new_full(1), queue);
+        assert_eq!([1, 2].into_iter().collect::<Static<u8, 0>>(), queue);
+    }
+
+    #[test]
+    fn indices_advance_modulo_capacity() {
+        let mut queue: Static<u8, 3> = Static::new();
+        assert_eq!((queue.read_index(), queue.write_index()), (0, 0));
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2]), 2);
+        assert_eq!((queue.read_index(), queue.write_index()), (0, 2));
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.enqueue(3), None);
+        assert_eq!((queue.read_index(), queue.write_index()), (1, 0));
+
+        assert_eq!(queue.enqueue(4), None);
+        assert_eq!((queue.read_index(), queue.write_index()), (1, 1));
+
+        assert_eq!(queue.bulk_dequeue(&mut [0; 2]), 2);
+        assert_eq!((queue.read_index(), queue.write_index()), (0, 1));
+    }
+
+    #[test]
+    fn contains_checks_both_segments() {
+        let mut queue: Static<u8, 3> = Static::new();
+        assert!(!queue.contains(&1));
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert!(queue.contains(&1));
+        assert!(queue.contains(&3));
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert!(!queue.contains(&1));
+
+        // This item wraps around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(4), None);
+        assert!(queue.contains(&2));
+        assert!(queue.contains(&4));
+        assert!(!queue.contains(&5));
+    }
+
+    #[test]
+    fn position_counts_from_the_front() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.position(|_| true), None);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[5, 6, 7]), 3);
+
+        assert_eq!(queue.position(|item| *item == 4), Some(0));
+        assert_eq!(queue.position(|item| *item == 6), Some(2));
+        assert_eq!(queue.position(|item| *item > 4), Some(1));
+        assert_eq!(queue.position(|item| *item == 1), None);
+    }
+
+    #[test]
+    fn bulk_enqueue_all_or_none_rejects_too_large_frames() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        assert_eq!(queue.dequeue(), Some(1));
+
+        assert!(!queue.bulk_enqueue_all_or_none(&[4, 5, 6]));
+        assert!(queue.iter().eq([2, 3]));
+
+        // Frames that fit are enqueued, even across the end of the ring-buffer.
+        assert!(queue.bulk_enqueue_all_or_none(&[4, 5]));
+        assert!(queue.iter().eq([2, 3, 4, 5]));
+        assert!(queue.bulk_enqueue_all_or_none(&[]));
+        assert!(!queue.bulk_enqueue_all_or_none(&[6]));
+    }
+
+    #[test]
+    fn bulk_enqueue_fills_both_segments() {
+        let mut queue: Static<u8, 6> = Static::new();
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+
+        // The free slots span the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[5, 6, 7, 8, 9, 10, 11]), 5);
+        assert!(queue.iter().eq([4, 5, 6, 7, 8, 9]));
+        assert_eq!(queue.bulk_enqueue(&[12]), 0);
+    }
+
+    #[test]
+    fn bulk_dequeue_drains_both_segments() {
+        let mut queue: Static<u8, 6> = Static::new();
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4, 5, 6]), 6);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 4]), 4);
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[7, 8, 9]), 3);
+
+        let mut buf = [0; 8];
+        assert_eq!(queue.bulk_dequeue(&mut buf), 5);
+        assert_eq!(buf[..5], [5, 6, 7, 8, 9]);
+        assert!(queue.is_empty());
+        assert_eq!(queue.bulk_dequeue(&mut buf), 0);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4, 5, 6]), 6);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 4]), 4);
+        assert_eq!(queue.bulk_enqueue(&[7, 8, 9]), 3);
+
+        let mut uninit_buf = [MaybeUninit::uninit(); 4];
+        assert_eq!(queue.bulk_dequeue_uninit(&mut uninit_buf), 4);
+        assert_eq!(unsafe { uninit_buf.assume_init_ref() }, &[5, 6, 7, 8]);
+        assert!(queue.iter().eq([9]));
+    }
+
+    #[test]
+    fn fills_free_slots_with_pattern() {
+        let mut queue: Static<u8, 10> = Static::new();
+        assert_eq!(queue.fill_with_pattern(&[]), 0);
+
+        assert_eq!(queue.fill_with_pattern(&[1, 2, 3]), 10);
+        assert!(queue.iter().eq([1, 2, 3, 1, 2, 3, 1, 2, 3, 1]));
+        assert_eq!(queue.fill_with_pattern(&[1, 2, 3]), 0);
+
+        // The pattern continues across the end of the ring-buffer.
+        assert_eq!(queue.bulk_dequeue(&mut [0; 5]), 5);
+        assert_eq!(queue.enqueue(0), None);
+        assert_eq!(queue.fill_with_pattern(&[4, 5]), 4);
+        assert!(queue.iter().eq([3, 1, 2, 3, 1, 0, 4, 5, 4, 5]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn equal_queues_hash_equally() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let hasher = RandomState::new();
+        let mut queue: Static<u8, 3> = Static::new();
+        let mut other: Static<u8, 4> = Static::new();
+
+        assert_eq!(queue.bulk_enqueue(&[0, 1, 2]), 3);
+        assert_eq!(queue.dequeue(), Some(0));
+        // This item wraps around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(3), None);
+        assert_eq!(other.bulk_enqueue(&[1, 2, 3]), 3);
+
+        assert_eq!(queue, other);
+        assert_eq!(hasher.hash_one(&queue), hasher.hash_one(&other));
+
+        assert_eq!(other.dequeue(), Some(1));
+        assert_ne!(hasher.hash_one(&queue), hasher.hash_one(&other));
+    }
+
+    #[test]
+    fn enqueues_slots_with_closure() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        let amount = queue.enqueue_slots_with(|writer| {
+            assert_eq!(writer.remaining(), 4);
+            assert_eq!(writer.bulk_push(&[1, 2]), 2);
+            assert_eq!(writer.push(3), None);
+        });
+        assert_eq!(amount, 3);
+        assert!(queue.iter().eq([1, 2, 3]));
+
+        // Only the kept items are enqueued.
+        assert_eq!(queue.dequeue(), Some(1));
+        let amount = queue.enqueue_slots_with(|writer| {
+            assert_eq!(writer.bulk_push(&[4, 5]), 1);
+            assert_eq!(writer.push(6), Some(6));
+            writer.commit_enqueued(0);
+        });
+        assert_eq!(amount, 0);
+        assert!(queue.iter().eq([2, 3]));
+
+        assert_eq!(queue.bulk_enqueue(&[4, 5]), 2);
+        assert_eq!(queue.enqueue_slots_with(|_| unreachable!()), 0);
+    }
+
+    #[test]
+    fn dequeues_items_with_closure() {
+        let mut queue: Static<u8, 8> = Static::new();
+        assert_eq!(queue.dequeue_items_with(|_| unreachable!()), None::<()>);
+
+        // Consume length-prefixed frames until an incomplete one is found.
+        assert_eq!(queue.bulk_enqueue(&[2, 7, 8, 1, 9, 3, 1]), 7);
+        let parse = |items: &[u8]| match items.split_first() {
+            Some((&len, rest)) if rest.len() >= len as usize => (
+                1 + len as usize,
+                Some(rest[..len as usize].iter().sum::<u8>()),
+            ),
+            _ => (0, None),
+        };
+
+        assert_eq!(queue.dequeue_items_with(parse), Some(Some(15)));
+        assert!(queue.iter().eq([1, 9, 3, 1]));
+        assert_eq!(queue.dequeue_items_with(parse), Some(Some(9)));
+        assert_eq!(queue.dequeue_items_with(parse), Some(None));
+        assert!(queue.iter().eq([3, 1]));
+    }
+
+    #[test]
+    fn truncate_removes_items_from_the_back() {
+        let mut queue: Static<u8, 5> = Static::new();
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4, 5]), 5);
+
+        queue.truncate(7);
+        assert_eq!(queue.len(), 5);
+
+        queue.truncate(2);
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.last(), Some(2));
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), None);
+
+        // The freed slots can be used again.
+        assert_eq!(queue.bulk_enqueue(&[6, 7, 8, 9, 10]), 5);
+        queue.truncate(0);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn discard_removes_items_from_the_front() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.discard(1), 0);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.discard(3), 3);
+        assert_eq!(queue.peek(), Some(4));
+
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[5, 6]), 2);
+        assert_eq!(queue.discard(10), 3);
+        assert!(queue.is_empty());
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.dequeue(), Some(7));
+    }
+
+    #[test]
+    fn bulk_dequeue_exact_requires_enough_items() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[5, 6]), 2);
+
+        let mut buf = [0; 4];
+        assert!(!queue.bulk_dequeue_exact(&mut buf));
+        assert_eq!(buf, [0; 4]);
+        assert!(queue.iter().eq([4, 5, 6]));
+
+        assert!(queue.bulk_dequeue_exact(&mut buf[..3]));
+        assert_eq!(buf, [4, 5, 6, 0]);
+        assert!(queue.is_empty());
+        assert!(queue.bulk_dequeue_exact(&mut []));
+    }
+
+    #[test]
+    fn enqueues_and_dequeues_at_compile_time() {
+        const DEQUEUED: [Option<u8>; 4] = {
+            let mut queue: Static<u8, 2> = Static::new();
+            assert!(queue.const_enqueue(1).is_none());
+            assert!(queue.const_enqueue(2).is_none());
+            assert!(queue.const_enqueue(3).is_some());
+
+            let first = queue.const_dequeue();
+            // This item wraps around the end of the ring-buffer.
+            assert!(queue.const_enqueue(3).is_none());
+
+            [
+                first,
+                queue.const_dequeue(),
+                queue.const_dequeue(),
+                queue.const_dequeue(),
+            ]
+        };
+
+        assert_eq!(DEQUEUED, [Some(1), Some(2), Some(3), None]);
+    }
+
+    #[test]
+    fn is_send_and_sync() {
+        fn assert_send_sync<Q: Send + Sync>() {}
+
+        assert_send_sync::<Static<u8, 8>>();
+    }
+
+    #[test]
+    fn rotates_like_vec_deque() {
+        use alloc::collections::VecDeque;
+
+        for n in 0..=5 {
+            // Cover both a full queue and a queue with free slots.
+            let mut full: Static<u8, 5> = Static::new();
+            let mut partial: Static<u8, 8> = Static::new();
+            let mut control = VecDeque::new();
+
+            // Wrap the items around the end of the ring-buffer.
+            assert_eq!(full.bulk_enqueue(&[0, 0, 0]), 3);
+            full.discard(3);
+            assert_eq!(partial.bulk_enqueue(&[0, 0, 0, 0, 0, 0]), 6);
+            partial.discard(6);
+            for item in 1..=5 {
+                assert_eq!(full.enqueue(item), None);
+                assert_eq!(partial.enqueue(item), None);
+                control.push_back(item);
+            }
+
+            full.rotate_left(n);
+            partial.rotate_left(n);
+            control.rotate_left(n);
+            assert!(full.iter().eq(control.iter().copied()));
+            assert!(partial.iter().eq(control.iter().copied()));
+
+            full.rotate_right(5 - n);
+            partial.rotate_right(5 - n);
+            control.rotate_right(5 - n);
+            assert!(full.iter().eq(control.iter().copied()));
+            assert!(partial.iter().eq(control.iter().copied()));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot rotate a queue by more places than it has items")]
+    fn rotate_panics_beyond_length() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.enqueue(1), None);
+
+        queue.rotate_right(2);
+    }
+
+    #[test]
+    fn appends_items_from_other_queue() {
+        let mut queue: Static<u8, 6> = Static::new();
+        let mut other: Static<u8, 6> = Static::new();
+
+        assert_eq!(queue.bulk_enqueue(&[0, 0, 0, 1, 2, 3]), 6);
+        queue.discard(3);
+        // Both queues wrap around the end of their ring-buffers.
+        assert_eq!(other.bulk_enqueue(&[0, 0, 0, 4]), 4);
+        other.discard(3);
+        assert_eq!(other.bulk_enqueue(&[5, 6, 7]), 3);
+
+        // Whatever does not fit remains in the other queue.
+        assert_eq!(queue.append(&mut other), 3);
+        assert!(queue.iter().eq([1, 2, 3, 4, 5, 6]));
+        assert!(other.iter().eq([7]));
+
+        assert_eq!(queue.append(&mut other), 0);
+        queue.discard(6);
+        assert_eq!(queue.append(&mut other), 1);
+        assert!(queue.iter().eq([7]));
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn reports_free_and_contiguous_free_slots() {
+        let mut queue: Static<u8, 6> = Static::new();
+        assert_eq!((queue.free(), queue.contiguous_free()), (6, 6));
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!((queue.free(), queue.contiguous_free()), (2, 2));
+
+        // The free slots wrap around the end of the ring-buffer.
+        queue.discard(3);
+        assert_eq!((queue.free(), queue.contiguous_free()), (5, 2));
+        assert_eq!(queue.expose_slots().map(|slots| slots.len()), Some(2));
+
+        // The items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[5, 6, 7]), 3);
+        assert_eq!((queue.free(), queue.contiguous_free()), (2, 2));
+
+        assert_eq!(queue.bulk_enqueue(&[8, 9]), 2);
+        assert_eq!((queue.free(), queue.contiguous_free()), (0, 0));
+    }
+
+    #[test]
+    fn enqueues_from_iter_without_losing_items() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        assert_eq!(queue.dequeue(), Some(b'f'));
+
+        // The free slots wrap around the end of the ring-buffer.
+        let mut iter = b"tofus".iter().copied();
+        assert_eq!(queue.enqueue_from_iter(&mut iter), 3);
+        assert!(queue.is_full());
+        assert!(iter.eq(*b"us"));
+
+        assert!(queue.iter().eq(*b"otof"));
+
+        // An exhausted iterator stops enqueueing before the queue is full.
+        assert_eq!(queue.dequeue(), Some(b'o'));
+        assert_eq!(queue.enqueue_from_iter(&mut core::iter::empty()), 0);
+        assert_eq!(queue.len(), 3);
+    }
+
+    #[test]
+    fn mutates_items_in_place_via_as_mut_slices() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert!(queue.as_mut_slices().0.is_empty());
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        assert_eq!(queue.bulk_enqueue(b"tof"), 2);
+
+        let (head, tail) = queue.as_mut_slices();
+        assert_eq!((&head[..], &tail[..]), (&b"fot"[..], &b"o"[..]));
+        head[0] = b'F';
+        tail[0] = b'O';
+
+        assert_eq!(queue.dequeue(), Some(b'F'));
+        assert_eq!(queue.dequeue(), Some(b'o'));
+        assert_eq!(queue.dequeue(), Some(b't'));
+        assert_eq!(queue.dequeue(), Some(b'O'));
+    }
+
+    #[test]
+    fn peeks_slices_across_the_end_of_the_ring_buffer() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.peek_slice(3), (&[][..], &[][..]));
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        assert_eq!(queue.dequeue(), Some(b'f'));
+        assert_eq!(queue.bulk_enqueue(b"tof"), 3);
+
+        let (head, tail) = queue.peek_slice(3);
+        assert_eq!((head, tail), (&b"ot"[..], &b"o"[..]));
+        assert_eq!([head, tail].concat(), b"oto");
+
+        // Peeking within the first segment, and beyond the length of the queue.
+        assert_eq!(queue.peek_slice(1), (&b"o"[..], &[][..]));
+        assert_eq!(queue.peek_slice(9), (&b"ot"[..], &b"of"[..]));
+        assert_eq!(queue.len(), 4);
+    }
+
+    #[test]
+    fn enqueues_only_if_predicate_holds() {
+        let mut queue: Static<u8, 3> = Static::new();
+
+        assert_eq!(queue.enqueue_if(1, |queue| queue.len() < 2), Ok(()));
+        assert_eq!(queue.enqueue_if(2, |queue| queue.len() < 2), Ok(()));
+        assert_eq!(queue.enqueue_if(3, |queue| queue.len() < 2), Err(3));
+        assert_eq!(queue.len(), 2);
+
+        // A full queue returns the item, even if the predicate holds.
+        assert_eq!(queue.enqueue(3), None);
+        assert_eq!(queue.enqueue_if(4, |_| true), Err(4));
+        assert!(queue.iter().eq([1, 2, 3]));
+    }
+
+    #[test]
+    fn creates_checked_queue_with_nonzero_capacity() {
+        let mut queue: Static<u8, 2> = Static::new_checked();
+
+        assert_eq!(queue.capacity(), 2);
+        assert_eq!(queue.enqueue(1), None);
+        assert_eq!(queue.dequeue(), Some(1));
+    }
+
+    #[test]
+    fn bulk_dequeues_exactly_into_uninit_buffer_or_not_at_all() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(b"to"), 2);
+
+        // Not enough items: neither the queue nor the buffer change.
+        let mut buf = [MaybeUninit::new(0); 5];
+        assert!(!queue.bulk_dequeue_exact_uninit(&mut buf));
+        assert_eq!(queue.len(), 4);
+        assert!(queue.iter().eq(*b"foto"));
+        assert_eq!(unsafe { buf.assume_init_ref() }, &[0; 5]);
+
+        let mut buf = [MaybeUninit::uninit(); 4];
+        assert!(queue.bulk_dequeue_exact_uninit(&mut buf));
+        assert_eq!(unsafe { buf.assume_init_ref() }, b"foto");
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn debug_validate_accepts_consistent_state() {
+        let mut queue: Static<u8, 4> = Static::new();
+        queue.debug_validate();
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        assert_eq!(queue.bulk_enqueue(b"to"), 2);
+        queue.debug_validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "read index out of bounds")]
+    fn debug_validate_panics_on_corrupted_read_index() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+
+        queue.read = 4;
+        queue.debug_validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "queue contains more items than its capacity")]
+    fn debug_validate_panics_on_corrupted_amount() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        queue.amount = 5;
+        queue.debug_validate();
+    }
+
+    #[test]
+    fn copies_items_to_slice_without_dequeueing() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(b"to"), 2);
+
+        let mut out = [0; 6];
+        assert_eq!(queue.copy_to_slice(&mut out), 4);
+        assert_eq!(&out[..4], b"foto");
+        assert_eq!(queue.len(), 4);
+
+        let mut out = [0; 3];
+        assert_eq!(queue.copy_to_slice(&mut out), 3);
+        assert_eq!(&out, b"fot");
+        assert!(queue.iter().eq(*b"foto"));
+    }
+
+    #[test]
+    fn enqueues_all_items_that_fit_exactly() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+
+        // These items wrap around the end of the ring-buffer.
+        queue.enqueue_all(b"to");
+        assert!(queue.is_full());
+        assert!(queue.iter().eq(*b"foto"));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot enqueue 5 items with only 4 free slots, 1 short")]
+    fn panics_on_enqueueing_all_items_that_do_not_fit() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        queue.enqueue_all(b"tofu!");
+    }
+
+    #[test]
+    fn reports_occupancy() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.occupancy(), 0.0);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2]), 2);
+        assert_eq!(queue.occupancy(), 0.5);
+
+        assert_eq!(queue.bulk_enqueue(&[3, 4]), 2);
+        assert_eq!(queue.occupancy(), 1.0);
+
+        let queue: Static<u8, 0> = Static::new();
+        assert_eq!(queue.occupancy(), 0.0);
+    }
+
+    #[test]
+    fn clone_from_overwrites_queue_in_place() {
+        let mut queue: Static<u8, 4> = Static::new();
+        let mut dst: Static<u8, 4> = Static::new();
+        assert_eq!(dst.bulk_enqueue(b"!!"), 2);
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        for round in 0..4 {
+            // Every round moves the items further around the ring-buffer.
+            assert!(queue.dequeue().is_some());
+            assert_eq!(queue.enqueue(round), None);
+
+            dst.clone_from(&queue);
+            assert_eq!(dst.capacity(), 4);
+            assert!(dst.iter().eq(queue.iter()));
+        }
+    }
+
+    #[test]
+    fn mutates_front_item_in_place() {
+        let mut queue: Static<u8, 3> = Static::new();
+        assert_eq!(queue.front_mut(), None);
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3]), 3);
+        *queue.front_mut().unwrap() += 10;
+        assert_eq!(queue.dequeue(), Some(11));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+
+        // These items wrap around to the start of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[4, 5]), 2);
+        assert_eq!(queue.dequeue(), Some(4));
+        *queue.front_mut().unwrap() = 50;
+        assert_eq!(queue.peek(), Some(50));
+        assert_eq!(queue.dequeue(), Some(50));
+    }
+
+    #[test]
+    fn bulk_enqueues_vectored_from_several_buffers() {
+        let mut queue: Static<u8, 6> = Static::new();
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+
+        // The queue fills up partway through the second buffer, which wraps around the end of
+        // the ring-buffer.
+        assert_eq!(queue.bulk_enqueue_vectored(&[b"to", b"fuqu", b"eues"]), 6);
+        assert!(queue.iter().eq(*b"tofuqu"));
+        assert_eq!(queue.bulk_enqueue_vectored(&[b"!"]), 0);
+    }
+
+    #[test]
+    fn bulk_dequeues_vectored_into_several_buffers() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(b"to"), 2);
+
+        let mut first = [0; 3];
+        let mut second = [0; 2];
+        assert_eq!(
+            queue.bulk_dequeue_vectored(&mut [&mut first, &mut second]),
+            4
+        );
+        assert_eq!(&first, b"fot");
+        assert_eq!(&second, b"o\0");
+        assert!(queue.is_empty());
+
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        let mut first = [0; 1];
+        let mut second = [0; 1];
+        assert_eq!(
+            queue.bulk_dequeue_vectored(&mut [&mut [], &mut first, &mut second]),
+            2
+        );
+        assert_eq!((&first, &second), (b"u", b"f"));
+        assert_eq!(queue.dequeue(), Some(b'o'));
+    }
+
+    #[test]
+    fn is_contiguous_matches_exposed_items() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert!(queue.is_contiguous());
+
+        assert_eq!(queue.bulk_enqueue(b"ufot"), 4);
+        assert!(queue.is_contiguous());
+        assert_eq!(queue.expose_items().map(|items| items.len()), Some(4));
+
+        assert_eq!(queue.bulk_dequeue(&mut [0; 2]), 2);
+        assert!(queue.is_contiguous());
+        assert_eq!(queue.expose_items().map(|items| items.len()), Some(2));
+
+        // This item wraps around the end of the ring-buffer.
+        assert_eq!(queue.enqueue(b'o'), None);
+        assert!(!queue.is_contiguous());
+        assert_eq!(queue.expose_items().map(|items| items.len()), Some(2));
+
+        assert_eq!(queue.make_contiguous(), b"oto");
+        assert!(queue.is_contiguous());
+        assert_eq!(queue.expose_items().map(|items| items.len()), Some(3));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn converts_wrapped_vec_deque_if_it_fits() {
+        use std::collections::VecDeque;
+
+        let mut deque = VecDeque::new();
+        deque.push_back(2);
+        deque.push_back(3);
+        // This item wraps around to the end of the deque's buffer.
+        deque.push_front(1);
+        assert!(!deque.as_slices().1.is_empty());
+
+        let queue = Static::<u8, 4>::try_from(deque.clone()).unwrap();
+        assert!(queue.iter().eq([1, 2, 3]));
+        assert!(queue.iter().eq(deque.iter().copied()));
+
+        assert_eq!(Static::<u8, 2>::try_from(deque.clone()), Err(deque));
+    }
+
+    #[test]
+    fn dequeues_while_predicate_holds() {
+        let mut queue: Static<u8, 6> = Static::new();
+        assert_eq!(queue.bulk_enqueue(&[1, 1, 1, 1]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 4]), 4);
+        // The run of even numbers wraps around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[2, 4, 6, 7, 8]), 5);
+
+        assert_eq!(queue.dequeue_while(|item| item % 2 == 0), 3);
+        assert_eq!(queue.peek(), Some(7));
+        assert_eq!(queue.dequeue_while(|item| item % 2 == 0), 0);
+        assert_eq!(queue.len(), 2);
+
+        assert_eq!(queue.dequeue_while(|_| true), 2);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn peeks_while_predicate_holds_across_the_end_of_the_ring_buffer() {
+        let mut queue: Static<u8, 6> = Static::new();
+        assert_eq!(queue.bulk_enqueue(&[1, 1, 1, 1]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 4]), 4);
+        // The run of even numbers spans both segments of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[2, 4, 6, 7, 8]), 5);
+
+        assert_eq!(queue.peek_while(|item| item % 2 == 0), 3);
+        assert_eq!(queue.peek_while(|_| true), 5);
+        assert_eq!(queue.peek_while(|item| *item > 2), 0);
+        assert_eq!(queue.len(), 5);
+
+        assert_eq!(queue.dequeue_while(|item| item % 2 == 0), 3);
+        assert_eq!(queue.peek_while(|item| item % 2 == 0), 0);
+    }
+
+    #[test]
+    fn converts_full_queue_into_array() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(queue.bulk_enqueue(b"ufo"), 3);
+        assert_eq!(queue.dequeue(), Some(b'u'));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(b"tu"), 2);
+        assert_eq!(queue.clone().into_array(), Some(*b"fotu"));
+
+        assert_eq!(queue.dequeue(), Some(b'f'));
+        assert_eq!(queue.into_array(), None);
+        assert_eq!(Static::<u8, 0>::new().into_array(), Some([]));
+    }
+
+    #[test]
+    fn test_debug_impl() {
+        let mut queue: Static<u8, 4> = Static::new();
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(queue.enqueue(21), None);
+        assert_eq!(queue.enqueue(196), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Static { len: 3, data: [7, 21, 196] }"
+        );
+
+        assert_eq!(queue.dequeue(), Some(7));
+        assert_eq!(format!("{:?}", queue), "Static { len: 2, data: [21, 196] }");
+
+        assert_eq!(queue.dequeue(), Some(21));
+        assert_eq!(format!("{:?}", queue), "Static { len: 1, data: [196] }");
+
+        assert_eq!(queue.enqueue(33), None);
+        assert_eq!(format!("{:?}", queue), "Static { len: 2, data: [196, 33] }");
+
+        assert_eq!(queue.enqueue(17), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Static { len: 3, data: [196, 33, 17] }"
+        );
+
+        assert_eq!(queue.enqueue(200), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Static { len: 4, data: [196, 33, 17, 200] }"
+        );
+    }
+
+    #[test]
+    fn test_debug_impl_at_boundaries() {
+        let mut queue: Static<u8, 4> = Static::new();
+        assert_eq!(format!("{:?}", queue), "Static { len: 0, data: [] }");
+
+        // A single item in the last slot of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+        assert_eq!(format!("{:?}", queue), "Static { len: 1, data: [4] }");
+
+        // A full queue that wraps around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[5, 6, 7]), 3);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Static { len: 4, data: [4, 5, 6, 7] }"
+        );
+
+        // A single item in the first slot, after the read index wrapped around.
+        assert_eq!(queue.bulk_dequeue(&mut [0; 3]), 3);
+        assert_eq!(format!("{:?}", queue), "Static { len: 1, data: [7] }");
+
+        // An empty queue whose read index is not zero.
+        assert_eq!(queue.dequeue(), Some(7));
+        assert_eq!(format!("{:?}", queue), "Static { len: 0, data: [] }");
+
+        let queue: Static<u8, 0> = Static::new();
+        assert_eq!(format!("{:?}", queue), "Static { len: 0, data: [] }");
     }
 }
@@ -0,0 +1,233 @@
+use core::fmt;
+use core::mem::MaybeUninit;
+
+use crate::{Queue, QueueFull, SlotWriter};
+
+/// A queue adaptor that hands every item dequeued from an inner queue to a closure, for example
+/// to log it. Enqueueing passes through to the inner queue unchanged.
+///
+/// The closure is called once per item, in FIFO order, no matter whether the item is dequeued
+/// individually or in bulk.
+pub struct Tee<Q, F> {
+    inner: Q,
+    f: F,
+}
+
+impl<Q: Queue, F: FnMut(&Q::Item)> Tee<Q, F> {
+    /// Wrap a queue, calling `f` on every item that is dequeued from it.
+    pub fn new(inner: Q, f: F) -> Self {
+        Tee { inner, f }
+    }
+
+    /// Return a reference to the inner queue.
+    pub fn get_ref(&self) -> &Q {
+        &self.inner
+    }
+
+    /// Unwrap the inner queue, discarding the closure.
+    pub fn into_inner(self) -> Q {
+        self.inner
+    }
+}
+
+impl<Q: Queue, F: FnMut(&Q::Item)> Queue for Tee<Q, F> {
+    type Item = Q::Item;
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    fn remaining(&self) -> usize {
+        self.inner.remaining()
+    }
+
+    fn is_full(&self) -> bool {
+        self.inner.is_full()
+    }
+
+    fn enqueue(&mut self, item: Self::Item) -> Option<Self::Item> {
+        self.inner.enqueue(item)
+    }
+
+    fn try_enqueue(&mut self, item: Self::Item) -> Result<(), QueueFull<Self::Item>> {
+        self.inner.try_enqueue(item)
+    }
+
+    fn expose_slots(&mut self) -> Option<&mut [MaybeUninit<Self::Item>]> {
+        self.inner.expose_slots()
+    }
+
+    unsafe fn consider_enqueued(&mut self, amount: usize) {
+        unsafe { self.inner.consider_enqueued(amount) }
+    }
+
+    fn enqueue_slots_with<G: FnOnce(&mut SlotWriter<'_, Self::Item>)>(&mut self, f: G) -> usize {
+        self.inner.enqueue_slots_with(f)
+    }
+
+    fn bulk_enqueue(&mut self, buffer: &[Self::Item]) -> usize {
+        self.inner.bulk_enqueue(buffer)
+    }
+
+    fn bulk_enqueue_remainder<'a>(&mut self, buffer: &'a [Self::Item]) -> &'a [Self::Item] {
+        self.inner.bulk_enqueue_remainder(buffer)
+    }
+
+    fn bulk_enqueue_all_or_none(&mut self, buffer: &[Self::Item]) -> bool {
+        self.inner.bulk_enqueue_all_or_none(buffer)
+    }
+
+    fn fill_with_pattern(&mut self, pattern: &[Self::Item]) -> usize {
+        self.inner.fill_with_pattern(pattern)
+    }
+
+    fn bulk_enqueue_from<P: Queue<Item = Self::Item>>(&mut self, other: &mut P) -> usize {
+        self.inner.bulk_enqueue_from(other)
+    }
+
+    fn enqueue_from_iter<I: Iterator<Item = Self::Item>>(&mut self, iter: &mut I) -> usize {
+        self.inner.enqueue_from_iter(iter)
+    }
+
+    /// Attempt to dequeue the next item, calling the closure on it.
+    ///
+    /// Will return `None` if the queue is empty at the time of calling.
+    fn dequeue(&mut self) -> Option<Self::Item> {
+        let item = self.inner.dequeue()?;
+        (self.f)(&item);
+
+        Some(item)
+    }
+
+    fn peek(&self) -> Option<Self::Item> {
+        self.inner.peek()
+    }
+
+    fn last(&self) -> Option<Self::Item> {
+        self.inner.last()
+    }
+
+    fn expose_items(&mut self) -> Option<&[Self::Item]> {
+        self.inner.expose_items()
+    }
+
+    /// Mark `amount` many items as having been dequeued, calling the closure on each of them.
+    ///
+    /// #### Invariants
+    ///
+    /// Callers must not mark items as dequeued that had not previously been exposed by
+    /// `expose_items`.
+    fn consider_dequeued(&mut self, amount: usize) {
+        if amount > 0 {
+            if let Some(items) = self.inner.expose_items() {
+                items[..amount].iter().for_each(&mut self.f);
+            }
+        }
+
+        self.inner.consider_dequeued(amount);
+    }
+
+    /// Dequeue a non-zero number of items by writing them into a given buffer and returning how
+    /// many items were dequeued, calling the closure on each of them.
+    ///
+    /// Will return `0` if the queue is empty at the time of calling.
+    fn bulk_dequeue(&mut self, buffer: &mut [Self::Item]) -> usize {
+        let amount = self.inner.bulk_dequeue(buffer);
+        buffer[..amount].iter().for_each(&mut self.f);
+
+        amount
+    }
+
+    /// Dequeue a non-zero number of items by writing them into a given buffer of possible
+    /// uninitialised memory and returning how many items were dequeued, calling the closure on
+    /// each of them.
+    ///
+    /// Will return `0` if the queue is empty at the time of calling.
+    fn bulk_dequeue_uninit(&mut self, buffer: &mut [MaybeUninit<Self::Item>]) -> usize {
+        let amount = self.inner.bulk_dequeue_uninit(buffer);
+        unsafe { buffer[..amount].assume_init_ref() }
+            .iter()
+            .for_each(&mut self.f);
+
+        amount
+    }
+}
+
+impl<Q: fmt::Debug, F> fmt::Debug for Tee<Q, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tee").field("inner", &self.inner).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::format;
+
+    use super::*;
+    use crate::Static;
+
+    #[test]
+    fn calls_closure_on_every_dequeued_item() {
+        let mut count = 0;
+        let mut sum = 0;
+        let mut queue = Tee::new(Static::<u8, 4>::new(), |item: &u8| {
+            count += 1;
+            sum += *item as usize;
+        });
+
+        assert_eq!(queue.bulk_enqueue(&[1, 2, 3, 4]), 4);
+        assert_eq!(queue.dequeue(), Some(1));
+        // These items wrap around the end of the ring-buffer.
+        assert_eq!(queue.bulk_enqueue(&[5]), 1);
+
+        let mut buf = [0; 2];
+        assert_eq!(queue.bulk_dequeue(&mut buf), 2);
+        assert_eq!(buf, [2, 3]);
+
+        let mut uninit_buf = [MaybeUninit::uninit(); 1];
+        assert_eq!(queue.bulk_dequeue_uninit(&mut uninit_buf), 1);
+
+        assert_eq!(queue.expose_items(), Some(&[5][..]));
+        queue.consider_dequeued(1);
+        assert_eq!(queue.dequeue(), None);
+
+        let _ = queue.into_inner();
+        assert_eq!(count, 5);
+        assert_eq!(sum, 15);
+    }
+
+    #[test]
+    fn does_not_call_closure_on_peeking_or_enqueueing() {
+        let mut count = 0;
+        let mut queue = Tee::new(Static::<u8, 2>::new(), |_: &u8| count += 1);
+
+        assert_eq!(queue.enqueue(1), None);
+        assert_eq!(queue.peek(), Some(1));
+        assert_eq!(queue.get_ref().len(), 1);
+
+        let inner = queue.into_inner();
+        assert_eq!(inner.len(), 1);
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_debug_impl() {
+        let mut queue = Tee::new(Static::<u8, 2>::new(), |_: &u8| {});
+
+        assert_eq!(queue.enqueue(7), None);
+        assert_eq!(
+            format!("{:?}", queue),
+            "Tee { inner: Static { len: 1, data: [7] } }"
+        );
+    }
+}